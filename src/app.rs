@@ -7,18 +7,13 @@ use egui_extras::TableBuilder;
 mod cont;
 mod disc;
 
-#[derive(PartialEq)]
+#[derive(PartialEq, Default)]
 enum Panel {
+    #[default]
     Cont,
     Disc,
 }
 
-impl Default for Panel {
-    fn default() -> Self {
-        Self::Cont
-    }
-}
-
 pub struct TemplateApp {
     cont_panel: ContPanel,
     disc_panel: DiscPanel,
@@ -142,75 +137,64 @@ fn show_summary_stats_table(ui: &mut Ui, stats: &SummaryStats) {
             });
         })
         .body(|body| {
-            body.rows(20.0, 5, |mut row| {
-                let row_index = row.index();
-                match row_index {
-                    0 => {
-                        row.col(|ui| {
-                            ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                                ui.label("Mean");
-                            });
-                        });
-                        row.col(|ui| {
-                            ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                                ui.label(stats.display_mean());
-                            });
-                        });
-                    }
-                    1 => {
-                        row.col(|ui| {
-                            ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                                ui.label("Variance");
-                            });
-                        });
-                        row.col(|ui| {
-                            ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                                ui.label(stats.display_variance());
-                            });
-                        });
-                    }
-                    2 => {
-                        row.col(|ui| {
-                            ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                                ui.label("Std. Dev.");
-                            });
-                        });
-                        row.col(|ui| {
-                            ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                                ui.label(stats.display_std_dev());
-                            });
-                        });
-                    }
-                    3 => {
-                        row.col(|ui| {
-                            ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                                ui.label("Entropy");
-                            });
-                        });
-                        row.col(|ui| {
-                            ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                                ui.label(stats.display_entropy());
-                            });
-                        });
-                    }
-                    4 => {
-                        row.col(|ui| {
-                            ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                                ui.label("Skewness");
-                            });
-                        });
-                        row.col(|ui| {
-                            ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                                ui.label(stats.display_skewness());
-                            });
-                        });
-                    }
-                    _ => {}
-                }
+            let rows = stats.rows();
+            body.rows(20.0, rows.len(), |mut row| {
+                let (label, value) = &rows[row.index()];
+                row.col(|ui| {
+                    ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
+                        ui.label(*label);
+                    });
+                });
+                row.col(|ui| {
+                    ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
+                        ui.label(value);
+                    });
+                });
             });
         });
 }
 
+/// Largest sample an order statistic is taken from.
+const MAX_SAMPLE: u64 = 1000;
+
+/// Size of a sample and the rank of the order statistic taken from it.
+#[derive(Clone, Copy, PartialEq)]
+struct OrderInputs {
+    n: u64,
+    k: u64,
+}
+
+impl OrderInputs {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(egui::Label::new("Sample size n:"));
+        ui.add(egui::DragValue::new(&mut self.n).range(1..=MAX_SAMPLE));
+        ui.add(egui::Label::new("Rank k:"));
+        ui.add(egui::DragValue::new(&mut self.k).range(1..=self.n))
+            .on_hover_text("1 is the smallest of the sample, n the largest");
+        ui.horizontal(|ui| {
+            if ui.button("Minimum").clicked() {
+                self.k = 1;
+            }
+            // the lower of the two middle values for an even sample
+            if ui.button("Median").clicked() {
+                self.k = self.n.div_ceil(2);
+            }
+            if ui.button("Maximum").clicked() {
+                self.k = self.n;
+            }
+        });
+        // a smaller sample must not leave the rank behind
+        self.k = self.k.clamp(1, self.n);
+    }
+}
+
+impl Default for OrderInputs {
+    /// The weakest of twenty components.
+    fn default() -> Self {
+        Self { n: 20, k: 1 }
+    }
+}
+
 fn powered_by_egui_and_eframe(ui: &mut egui::Ui) {
     ui.horizontal(|ui| {
         ui.spacing_mut().item_spacing.x = 0.0;
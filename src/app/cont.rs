@@ -1,131 +1,169 @@
 use super::linspace;
 use crate::distr::cont_distr::DistrTypes;
 use egui::emath::Numeric;
-use std::error::Error;
+use std::{cell::RefCell, error::Error, f64::consts::PI, fmt::Display, ops::RangeInclusive};
 use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
 
-use egui_plot::{Line, Plot, PlotPoints};
+use egui_plot::{Line, LineStyle, Plot, PlotPoints};
 use statrs::distribution::*;
 
-use super::show_summary_stats_table;
+use super::{OrderInputs, show_summary_stats_table};
 use crate::distr::cont_distr::*;
+use crate::distr::expr::Expr;
 use crate::distr::*;
 
 pub struct ContPanel {
-    par1: f64,
-    par2: f64,
-    par3: f64,
+    base: ContSelector,
+    mode: Mode,
+    components: Vec<Component>,
+    // the mixture integrates its entropy, it is only redone when a
+    // component or weight changes
+    mixture_cache: RefCell<Option<(Vec<Component>, Mixture)>>,
+    show_components: bool,
+    combine: CombineInputs,
+    // building the combination is expensive, it is only redone when its
+    // inputs change
+    combine_cache: RefCell<Option<(CombineInputs, Box<dyn Reusable>)>>,
+    normal_approx: bool,
+    order: OrderInputs,
+    // the order statistic integrates its moments, it is only redone when X
+    // or the sample change
+    order_cache: RefCell<Option<((ContSelector, OrderInputs), OrderStatistic)>>,
+    show_parent: bool,
+    // text of g in Y = g(X)
+    transform: String,
+    // the transformation draws from X where g is not monotone, it is only
+    // redone when X or g change
+    transform_cache: RefCell<Option<((ContSelector, String), Transformed)>>,
+    polar: bool,
+    truncated: bool,
+    lower: f64,
+    upper: f64,
+}
+
+/// What the panel plots, a single distribution or one built from others.
+#[derive(PartialEq, Clone, Copy)]
+enum Mode {
+    Single,
+    Mixture,
+    Combine,
+    OrderStatistic,
+    Transform,
+}
+
+/// A distribution picked from the catalogue together with its parameters.
+#[derive(Clone, PartialEq)]
+struct ContSelector {
+    pars: Vec<f64>,
     selected_distr: DistrTypes,
     defaults: Vec<MixedParam>,
+    binding: Option<Binding<Compound>>,
+}
+
+/// A parameter drawn from a continuous distribution of its own rather than
+/// fixed, which turns the distribution it belongs to into a compound one.
+pub(super) struct Binding<D> {
+    slot: usize,
+    mixing: Box<ContSelector>,
+    // integrating over the parameter is expensive, the marginal is only
+    // redone when the parameters change
+    cache: RefCell<Option<Marginal<D>>>,
+}
+
+/// Parameters and mixing distribution a marginal was built for, and the
+/// marginal.
+type Marginal<D> = (Vec<f64>, Box<ContSelector>, D);
+
+/// Two independent variables and how they are combined.
+#[derive(Clone, PartialEq)]
+struct CombineInputs {
+    x: ContSelector,
+    y: ContSelector,
+    op: Operation,
+    // copies of `X` in an iid sum
+    n: u64,
+}
+
+#[derive(PartialEq, Clone, Copy, EnumIter)]
+enum Operation {
+    Sum,
+    IidSum,
+    Product,
+    Ratio,
+    Minimum,
+    Maximum,
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operation::Sum => write!(f, "X + Y"),
+            Operation::IidSum => write!(f, "X₁ + … + Xₙ"),
+            Operation::Product => write!(f, "X · Y"),
+            Operation::Ratio => write!(f, "X / Y"),
+            Operation::Minimum => write!(f, "min(X, Y)"),
+            Operation::Maximum => write!(f, "max(X, Y)"),
+        }
+    }
+}
+
+/// Most copies of `X` in an iid sum.
+const MAX_COPIES: u64 = 500;
+
+/// Distributions that are slow to build but cheap to copy, so they can be
+/// kept between frames.
+trait Reusable {
+    fn boxed(&self) -> Box<dyn Cont>;
+}
+
+impl<D: Cont + Clone + 'static> Reusable for D {
+    fn boxed(&self) -> Box<dyn Cont> {
+        Box::new(self.clone())
+    }
+}
+
+/// Weighted component of a mixture.
+#[derive(Clone, PartialEq)]
+struct Component {
+    selector: ContSelector,
+    weight: f64,
 }
 
 impl ContPanel {
     pub fn side_panel(&mut self, ui: &mut egui::Ui) {
-        let mut reset = false;
-        egui::ComboBox::from_label("Select a distribution")
-            .selected_text(format!("{}", self.selected_distr))
-            .height(1000.)
-            .show_ui(ui, |ui| {
-                for d in DistrTypes::iter() {
-                    if ui
-                        .selectable_value(&mut self.selected_distr, d.clone(), format!("{}", d))
-                        .clicked()
-                    {
-                        reset = true;
-                    }
-                }
-            });
-
-        if reset {
-            self.defaults = self.selected_distr.get_defaults();
-            for (i, p) in self.defaults.iter().enumerate() {
-                let par = match p {
-                    MixedParam::_Signed { param: p } => p.default.to_f64(),
-                    MixedParam::Unsigned { param: p } => p.default.to_f64(),
-                    MixedParam::Float { param: p } => p.default,
-                };
-                if i == 0 {
-                    self.par1 = par
-                } else if i == 1 {
-                    self.par2 = par
-                } else if i == 2 {
-                    self.par3 = par
-                } else {
-                    panic!()
-                }
-            }
+        ui.horizontal_wrapped(|ui| {
+            ui.selectable_value(&mut self.mode, Mode::Single, "Single");
+            ui.selectable_value(&mut self.mode, Mode::Mixture, "Mixture");
+            ui.selectable_value(&mut self.mode, Mode::Combine, "Combine");
+            ui.selectable_value(&mut self.mode, Mode::OrderStatistic, "Order statistic");
+            ui.selectable_value(&mut self.mode, Mode::Transform, "Transform");
+        });
+        ui.add_space(10.0);
+        match self.mode {
+            Mode::Single => self.base.ui(ui),
+            Mode::Mixture => self.mixture_ui(ui),
+            Mode::Combine => self.combine_ui(ui),
+            Mode::OrderStatistic => self.order_ui(ui),
+            Mode::Transform => self.transform_ui(ui),
         }
 
         ui.add_space(10.0);
-        for (i, p) in self.defaults.clone().into_iter().enumerate() {
-            match p {
-                MixedParam::Unsigned { param: p } => {
-                    ui.add(egui::Label::new(format!("{}:", p.name)));
-                    if i == 0 {
-                        ui.add(
-                            egui::DragValue::new(&mut self.par1)
-                                .range(p.range)
-                                .speed(p.speed),
-                        )
-                        .on_hover_text(p.desc.unwrap_or("".to_owned()));
-                    } else if i == 1 {
-                        ui.add(
-                            egui::DragValue::new(&mut self.par2)
-                                .range(p.range)
-                                .speed(p.speed),
-                        )
-                        .on_hover_text(p.desc.unwrap_or("".to_owned()));
-                    } else if i == 2 {
-                        ui.add(
-                            egui::DragValue::new(&mut self.par3)
-                                .range(p.range)
-                                .speed(p.speed),
-                        )
-                        .on_hover_text(p.desc.unwrap_or("".to_owned()));
-                    } else {
-                        panic!()
-                    }
-                }
-                MixedParam::Float { param: p } => {
-                    ui.add(egui::Label::new(format!("{}:", p.name)));
-                    if i == 0 {
-                        ui.add(
-                            egui::DragValue::new(&mut self.par1)
-                                .range(p.range)
-                                .speed(p.speed),
-                        )
-                        .on_hover_text(p.desc.unwrap_or("".to_owned()));
-                    } else if i == 1 {
-                        ui.add(
-                            egui::DragValue::new(&mut self.par2)
-                                .range(p.range)
-                                .speed(p.speed),
-                        )
-                        .on_hover_text(p.desc.unwrap_or("".to_owned()));
-                    } else if i == 2 {
-                        ui.add(
-                            egui::DragValue::new(&mut self.par3)
-                                .range(p.range)
-                                .speed(p.speed),
-                        )
-                        .on_hover_text(p.desc.unwrap_or("".to_owned()));
-                    } else {
-                        panic!()
-                    }
-                }
-                MixedParam::_Signed { param: p } => {
-                    ui.add(egui::Label::new(format!("{}:", p.name)));
-                    if i == 0 {
-                        ui.add(egui::DragValue::new(&mut self.par1).range(p.range));
-                    } else if i == 1 {
-                        ui.add(egui::DragValue::new(&mut self.par2).range(p.range));
-                    } else if i == 2 {
-                        ui.add(egui::DragValue::new(&mut self.par3).range(p.range));
-                    } else {
-                        panic!()
-                    }
-                }
-            }
+        if ui.checkbox(&mut self.truncated, "Truncate").changed()
+            && self.truncated
+            && let Ok(d) = self.get_base_distr()
+        {
+            // start out with the central 90% of the mass
+            self.lower = d.inverse_cdf(0.05);
+            self.upper = d.inverse_cdf(0.95);
+        }
+        if self.truncated {
+            ui.add(egui::Label::new("Lower:"));
+            ui.add(egui::DragValue::new(&mut self.lower).speed(0.1))
+                .on_hover_text("<Upper");
+            ui.add(egui::Label::new("Upper:"));
+            ui.add(egui::DragValue::new(&mut self.upper).speed(0.1))
+                .on_hover_text(">Lower");
         }
         ui.add_space(10.0);
         ui.separator();
@@ -135,7 +173,10 @@ impl ContPanel {
         // Summary statistics
         let distr = self.get_distr();
         if let Ok(d) = distr {
-            let summary = SummaryStats::new(&(*d));
+            let summary = match d.as_circular() {
+                Some(c) => SummaryStats::circular(c),
+                None => SummaryStats::new(&(*d)),
+            };
             show_summary_stats_table(ui, &summary);
             ui.add_space(10.0);
         }
@@ -143,13 +184,24 @@ impl ContPanel {
     pub fn central_panel(&mut self, ui: &mut egui::Ui) {
         let distr = self.get_distr();
         match distr {
-            Err(_) => {}
+            Err(e) => {
+                ui.colored_label(
+                    ui.visuals().error_fg_color,
+                    format!("Invalid parameters: {e}"),
+                );
+            }
             Ok(d) => {
-                let (min, max) = match self.selected_distr {
-                    DistrTypes::Cauchy => {
-                        (self.par1 - 10. * self.par2, self.par1 + 10. * self.par2)
+                if d.as_circular().is_some() {
+                    ui.checkbox(&mut self.polar, "Polar plot");
+                    if self.polar {
+                        show_polar_plot(ui, d.as_ref());
+                        return;
                     }
-                    _ => (d.inverse_cdf(0.001), d.inverse_cdf(0.999)),
+                }
+                let (min, max) = match d.as_circular() {
+                    // show the whole circle
+                    Some(_) => (-PI, PI),
+                    None => get_min_max(d.as_ref()),
                 };
                 if min != -f64::INFINITY && max != f64::INFINITY {
                     // CDF
@@ -166,11 +218,18 @@ impl ContPanel {
                         }
                     }
                     max_y += 0.2;
-                    let pdf_points: PlotPoints<'_> = x.zip(pdfs).map(|(x, y)| [x, y]).collect();
+                    let pdf_points: PlotPoints<'_> =
+                        x.clone().zip(pdfs).map(|(x, y)| [x, y]).collect();
                     let pdf = Line::new("Default", pdf_points).name("PDF");
+                    let mut overlays = self.component_lines(x.clone());
+                    overlays.extend(self.normal_line(d.as_ref(), x.clone()));
+                    overlays.extend(self.parent_line(x));
                     Plot::new("MainPlot").view_aspect(2.0).show(ui, |plot_ui| {
                         plot_ui.line(pdf);
                         plot_ui.line(cdf);
+                        for line in overlays {
+                            plot_ui.line(line);
+                        }
                         // allow x axis to use auto bounds
                         plot_ui.set_plot_bounds(egui_plot::PlotBounds::from_min_max(
                             [min - 1., 0.0],
@@ -183,46 +242,595 @@ impl ContPanel {
     }
 }
 
+impl ContPanel {
+    fn mixture_ui(&mut self, ui: &mut egui::Ui) {
+        let mut remove = None;
+        for (i, c) in self.components.iter_mut().enumerate() {
+            ui.push_id(i, |ui| {
+                ui.horizontal(|ui| {
+                    ui.strong(format!("Component {}", i + 1));
+                    if ui.small_button("🗙").on_hover_text("Remove").clicked() {
+                        remove = Some(i);
+                    }
+                });
+                c.selector.ui(ui);
+                ui.add(egui::Label::new("Weight:"));
+                ui.add(
+                    egui::DragValue::new(&mut c.weight)
+                        .range(0.0..=f64::MAX)
+                        .speed(0.05),
+                )
+                .on_hover_text(">=0, normalized to sum to 1");
+                ui.add_space(10.0);
+            });
+        }
+        // keep at least one component so the mixture stays defined
+        if let Some(i) = remove
+            && self.components.len() > 1
+        {
+            self.components.remove(i);
+        }
+        if ui.button("➕ Add component").clicked() {
+            self.components
+                .push(Component::new(ContSelector::new(DistrTypes::Normal)));
+        }
+        ui.checkbox(&mut self.show_components, "Show components");
+    }
+
+    /// Dashed densities of the weighted mixture components, if they are shown.
+    /// Truncation would rescale them, so they are left out then.
+    fn component_lines(&self, x: impl Iterator<Item = f64> + Clone) -> Vec<Line<'_>> {
+        if self.mode != Mode::Mixture || !self.show_components || self.truncated {
+            return Vec::new();
+        }
+        let Ok(mixture) = self.get_mixture() else {
+            return Vec::new();
+        };
+        mixture
+            .components()
+            .enumerate()
+            .map(|(i, (w, c))| {
+                let points: PlotPoints<'_> = x.clone().map(|x| [x, w * c.pdf(x)]).collect();
+                Line::new("Default", points)
+                    .name(format!("Component {}", i + 1))
+                    .style(LineStyle::dashed_loose())
+            })
+            .collect()
+    }
+
+    fn combine_ui(&mut self, ui: &mut egui::Ui) {
+        let inputs = &mut self.combine;
+        ui.horizontal_wrapped(|ui| {
+            for op in Operation::iter() {
+                ui.selectable_value(&mut inputs.op, op, op.to_string());
+            }
+        });
+        ui.add_space(10.0);
+        ui.strong("X");
+        ui.push_id("x", |ui| inputs.x.ui(ui));
+        ui.add_space(10.0);
+        if inputs.op == Operation::IidSum {
+            ui.add(egui::Label::new("n:"));
+            ui.add(egui::DragValue::new(&mut inputs.n).range(1..=MAX_COPIES))
+                .on_hover_text("Number of independent copies of X");
+        } else {
+            ui.strong("Y");
+            ui.push_id("y", |ui| inputs.y.ui(ui));
+        }
+        if matches!(inputs.op, Operation::Sum | Operation::IidSum) {
+            ui.checkbox(&mut self.normal_approx, "Show normal approximation");
+        }
+    }
+
+    /// Dashed density of the normal distribution with the same mean and
+    /// variance as the sum, if it is shown.
+    fn normal_line(&self, d: &dyn Cont, x: impl Iterator<Item = f64>) -> Option<Line<'_>> {
+        let is_sum = matches!(self.combine.op, Operation::Sum | Operation::IidSum);
+        if self.mode != Mode::Combine || !is_sum || !self.normal_approx || self.truncated {
+            return None;
+        }
+        let normal = Normal::new(d.mean()?, d.std_dev()?).ok()?;
+        let points: PlotPoints<'_> = x.map(|x| [x, normal.pdf(x)]).collect();
+        Some(
+            Line::new("Default", points)
+                .name("Normal approximation")
+                .style(LineStyle::dashed_loose()),
+        )
+    }
+
+    fn order_ui(&mut self, ui: &mut egui::Ui) {
+        self.base.ui(ui);
+        ui.add_space(10.0);
+        self.order.ui(ui);
+        ui.checkbox(&mut self.show_parent, "Show single draw");
+    }
+
+    /// Dashed density of a single draw from the sample, if it is shown.
+    fn parent_line(&self, x: impl Iterator<Item = f64>) -> Option<Line<'_>> {
+        if self.mode != Mode::OrderStatistic || !self.show_parent || self.truncated {
+            return None;
+        }
+        let parent = self.base.get_distr().ok()?;
+        let points: PlotPoints<'_> = x.map(|x| [x, parent.pdf(x)]).collect();
+        Some(
+            Line::new("Default", points)
+                .name("Single draw")
+                .style(LineStyle::dashed_loose()),
+        )
+    }
+
+    fn transform_ui(&mut self, ui: &mut egui::Ui) {
+        ui.strong("X");
+        self.base.ui(ui);
+        ui.add_space(10.0);
+        ui.label("Y = g(X) with g(x) =");
+        ui.text_edit_singleline(&mut self.transform).on_hover_text(
+            "For example exp(x), x^2 or 1/x. Knows + - * / ^, parentheses, pi, e \
+             and functions such as exp, ln, sqrt, abs, sin or atan",
+        );
+    }
+}
+
+/// Draws the density of a circular distribution as a radial offset from the
+/// unit circle, together with its mean resultant vector.
+fn show_polar_plot(ui: &mut egui::Ui, d: &dyn Cont) {
+    let theta = linspace::<f64>(-PI, PI, 1000);
+    let circle: PlotPoints<'_> = theta.clone().map(|t| [t.cos(), t.sin()]).collect();
+    let density: PlotPoints<'_> = theta
+        .map(|t| {
+            let r = 1.0 + d.pdf(t);
+            [r * t.cos(), r * t.sin()]
+        })
+        .collect();
+    Plot::new("PolarPlot")
+        .view_aspect(2.0)
+        .data_aspect(1.0)
+        .show(ui, |plot_ui| {
+            plot_ui.line(
+                Line::new("Default", circle)
+                    .name("Unit circle")
+                    .style(LineStyle::dashed_loose()),
+            );
+            plot_ui.line(Line::new("Default", density).name("PDF"));
+            if let Some(c) = d.as_circular() {
+                let (mu, r) = (c.mean_direction(), c.mean_resultant_length());
+                let resultant = vec![[0.0, 0.0], [r * mu.cos(), r * mu.sin()]];
+                plot_ui.line(Line::new("Default", resultant).name("Mean resultant"));
+            }
+        });
+}
+
+/// Plot range covering the central 99.8% of the mass. Heavy tails would
+/// squeeze the bulk of the density into a few pixels, so the range is capped
+/// at five interquartile ranges beyond the quartiles.
+fn get_min_max(distr: &dyn Cont) -> (f64, f64) {
+    let (q1, q3) = (distr.inverse_cdf(0.25), distr.inverse_cdf(0.75));
+    let iqr = q3 - q1;
+    let min = distr.inverse_cdf(0.001).max(q1 - 5. * iqr);
+    let max = distr.inverse_cdf(0.999).min(q3 + 5. * iqr);
+    (min, max)
+}
+
 impl Default for ContPanel {
     fn default() -> Self {
-        let defaults = DistrTypes::Normal.get_defaults();
-        let par1 = match defaults[0].clone() {
-            MixedParam::Float { param: p } => p.default,
-            _ => panic!(),
-        };
-        let par2 = match defaults[1].clone() {
-            MixedParam::Float { param: p } => p.default,
-            _ => panic!(),
+        // two well separated normals make the bimodal example
+        let mut left = ContSelector::new(DistrTypes::Normal);
+        let mut right = ContSelector::new(DistrTypes::Normal);
+        left.pars[0] = -2.0;
+        right.pars[0] = 2.0;
+        // a skewed X shows how slowly the sum loses its skew
+        let combine = CombineInputs {
+            x: ContSelector::new(DistrTypes::Exp),
+            y: ContSelector::new(DistrTypes::Uniform),
+            op: Operation::Sum,
+            n: 10,
         };
         Self {
-            par1,
-            par2,
-            par3: 0.0,
-            selected_distr: DistrTypes::Normal,
-            defaults,
+            base: ContSelector::new(DistrTypes::Normal),
+            mode: Mode::Single,
+            components: vec![Component::new(left), Component::new(right)],
+            mixture_cache: RefCell::new(None),
+            show_components: true,
+            combine,
+            combine_cache: RefCell::new(None),
+            normal_approx: true,
+            order: OrderInputs::default(),
+            order_cache: RefCell::new(None),
+            show_parent: true,
+            transform: "exp(x)".to_owned(),
+            transform_cache: RefCell::new(None),
+            polar: false,
+            truncated: false,
+            lower: -1.0,
+            upper: 1.0,
+        }
+    }
+}
+
+impl Component {
+    fn new(selector: ContSelector) -> Self {
+        Self {
+            selector,
+            weight: 1.0,
         }
     }
 }
 
+fn default_pars(defaults: &[MixedParam]) -> Vec<f64> {
+    defaults
+        .iter()
+        .map(|p| match p {
+            MixedParam::Signed { param: p } => p.default.to_f64(),
+            MixedParam::Unsigned { param: p } => p.default.to_f64(),
+            MixedParam::Float { param: p } => p.default,
+            MixedParam::FloatList { .. } | MixedParam::UnsignedList { .. } => {
+                unreachable!("no continuous distribution takes a list parameter")
+            }
+        })
+        .collect()
+}
+
 impl ContPanel {
     fn get_distr(&self) -> Result<Box<dyn Cont>, Box<dyn Error>> {
+        let distr = self.get_base_distr()?;
+        if !self.truncated {
+            return Ok(distr);
+        }
+        Ok(Box::new(Truncated::new(distr, self.lower, self.upper)?))
+    }
+
+    /// The distribution of the selected mode before truncation.
+    fn get_base_distr(&self) -> Result<Box<dyn Cont>, Box<dyn Error>> {
+        match self.mode {
+            Mode::Single => self.base.get_distr(),
+            Mode::Mixture => Ok(Box::new(self.get_mixture()?)),
+            Mode::Combine => self.get_combined(),
+            Mode::OrderStatistic => Ok(Box::new(self.get_order_statistic()?)),
+            Mode::Transform => Ok(Box::new(self.get_transformed()?)),
+        }
+    }
+
+    /// The cached order statistic if X and the sample are unchanged,
+    /// otherwise a new one, which replaces it.
+    fn get_order_statistic(&self) -> Result<OrderStatistic, Box<dyn Error>> {
+        let inputs = (self.base.clone(), self.order);
+        if let Some((cached, distr)) = &*self.order_cache.borrow()
+            && *cached == inputs
+        {
+            return Ok(distr.clone());
+        }
+        let distr = OrderStatistic::new(self.base.get_distr()?, self.order.n, self.order.k)?;
+        *self.order_cache.borrow_mut() = Some((inputs, distr.clone()));
+        Ok(distr)
+    }
+
+    /// The cached transformation if X and g are unchanged, otherwise a new
+    /// one, which replaces it.
+    fn get_transformed(&self) -> Result<Transformed, Box<dyn Error>> {
+        let inputs = (self.base.clone(), self.transform.clone());
+        if let Some((cached, distr)) = &*self.transform_cache.borrow()
+            && *cached == inputs
+        {
+            return Ok(distr.clone());
+        }
+        let distr = Transformed::new(self.base.get_distr()?, Expr::parse(&self.transform)?)?;
+        *self.transform_cache.borrow_mut() = Some((inputs, distr.clone()));
+        Ok(distr)
+    }
+
+    /// The cached mixture if its components are unchanged, otherwise a new
+    /// one, which replaces it.
+    fn get_mixture(&self) -> Result<Mixture, Box<dyn Error>> {
+        if let Some((cached, distr)) = &*self.mixture_cache.borrow()
+            && *cached == self.components
+        {
+            return Ok(distr.clone());
+        }
+        let components = self
+            .components
+            .iter()
+            .map(|c| Ok((c.weight, c.selector.get_distr()?)))
+            .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+        let distr = Mixture::new(components)?;
+        *self.mixture_cache.borrow_mut() = Some((self.components.clone(), distr.clone()));
+        Ok(distr)
+    }
+
+    fn get_combined(&self) -> Result<Box<dyn Cont>, Box<dyn Error>> {
+        let inputs = &self.combine;
+        let x = inputs.x.get_distr()?;
+        // Y is left alone for the iid sum, it may well be invalid
+        let y = || inputs.y.get_distr();
+        match inputs.op {
+            Operation::Sum => {
+                let y = y()?;
+                self.cached(|| {
+                    Ok(Box::new(Convolution::new(&[
+                        (x.as_ref(), 1),
+                        (y.as_ref(), 1),
+                    ])?))
+                })
+            }
+            Operation::IidSum => {
+                self.cached(|| Ok(Box::new(Convolution::new(&[(x.as_ref(), inputs.n)])?)))
+            }
+            Operation::Product => {
+                let y = y()?;
+                self.cached(|| Ok(Box::new(Product::new(x.as_ref(), y.as_ref()))))
+            }
+            Operation::Ratio => {
+                let y = y()?;
+                self.cached(|| Ok(Box::new(Product::ratio(x.as_ref(), y.as_ref()))))
+            }
+            Operation::Minimum => {
+                let y = y()?;
+                self.cached(|| Ok(Box::new(Extremum::minimum(x, y))))
+            }
+            Operation::Maximum => {
+                let y = y()?;
+                self.cached(|| Ok(Box::new(Extremum::maximum(x, y))))
+            }
+        }
+    }
+
+    /// The cached combination if its inputs are unchanged, otherwise the one
+    /// from `build`, which replaces it in the cache.
+    fn cached(
+        &self,
+        build: impl FnOnce() -> Result<Box<dyn Reusable>, Box<dyn Error>>,
+    ) -> Result<Box<dyn Cont>, Box<dyn Error>> {
+        if let Some((inputs, distr)) = &*self.combine_cache.borrow()
+            && *inputs == self.combine
+        {
+            return Ok(distr.boxed());
+        }
+        let distr = build()?;
+        let boxed = distr.boxed();
+        *self.combine_cache.borrow_mut() = Some((self.combine.clone(), distr));
+        Ok(boxed)
+    }
+}
+
+impl<D> Clone for Binding<D> {
+    /// The copy starts out without a cached marginal.
+    fn clone(&self) -> Self {
+        Self {
+            slot: self.slot,
+            mixing: self.mixing.clone(),
+            cache: RefCell::new(None),
+        }
+    }
+}
+
+impl<D> PartialEq for Binding<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.slot == other.slot && self.mixing == other.mixing
+    }
+}
+
+impl<D: Clone> Binding<D> {
+    /// Draws the parameter in `slot` from a distribution with mean `value`
+    /// that stays within its `range`: a Beta for probabilities, a Gamma for
+    /// positive parameters and a Normal otherwise.
+    pub(super) fn new(slot: usize, value: f64, range: &RangeInclusive<f64>) -> Self {
+        let (distr, pars) =
+            if *range.start() >= 0.0 && *range.end() <= 1.0 && value > 0.0 && value < 1.0 {
+                (DistrTypes::Beta, vec![10.0 * value, 10.0 * (1.0 - value)])
+            } else if *range.start() >= 0.0 && value > 0.0 {
+                (DistrTypes::Gamma, vec![10.0, 10.0 / value])
+            } else {
+                (DistrTypes::Normal, vec![value, 1.0])
+            };
+        let mut mixing = ContSelector::new(distr);
+        mixing.pars = pars;
+        Self {
+            slot,
+            mixing: Box::new(mixing),
+            cache: RefCell::new(None),
+        }
+    }
+
+    pub(super) fn slot(&self) -> usize {
+        self.slot
+    }
+
+    /// Picker for the mixing distribution, indented below the parameter.
+    /// Returns `false` once the parameter is to be fixed again.
+    pub(super) fn ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut keep = true;
+        ui.horizontal(|ui| {
+            ui.label("drawn from");
+            if ui
+                .small_button("🗙")
+                .on_hover_text("Fix the parameter")
+                .clicked()
+            {
+                keep = false;
+            }
+        });
+        ui.indent(self.slot, |ui| self.mixing.ui(ui));
+        keep
+    }
+
+    /// The cached marginal if `pars` and the mixing distribution are
+    /// unchanged, otherwise the one from `build`, which replaces it.
+    pub(super) fn marginal(
+        &self,
+        pars: Vec<f64>,
+        build: impl FnOnce(&dyn Cont) -> Result<D, Box<dyn Error>>,
+    ) -> Result<D, Box<dyn Error>> {
+        if let Some((cached, mixing, distr)) = &*self.cache.borrow()
+            && *cached == pars
+            && *mixing == self.mixing
+        {
+            return Ok(distr.clone());
+        }
+        let distr = build(self.mixing.get_distr()?.as_ref())?;
+        *self.cache.borrow_mut() = Some((pars, self.mixing.clone(), distr.clone()));
+        Ok(distr)
+    }
+}
+
+/// Button that draws a parameter from a distribution instead.
+pub(super) fn bind_button(ui: &mut egui::Ui) -> bool {
+    ui.small_button("~")
+        .on_hover_text("Draw the parameter from a distribution")
+        .clicked()
+}
+
+impl ContSelector {
+    fn new(selected_distr: DistrTypes) -> Self {
+        let defaults = selected_distr.get_defaults();
+        Self {
+            pars: default_pars(&defaults),
+            selected_distr,
+            defaults,
+            binding: None,
+        }
+    }
+
+    /// Distribution picker and the inputs for its parameters.
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let mut reset = false;
+        egui::ComboBox::from_label("Select a distribution")
+            .selected_text(format!("{}", self.selected_distr))
+            .height(1000.)
+            .show_ui(ui, |ui| {
+                for d in DistrTypes::iter() {
+                    if ui
+                        .selectable_value(&mut self.selected_distr, d.clone(), format!("{}", d))
+                        .clicked()
+                    {
+                        reset = true;
+                    }
+                }
+            });
+
+        if reset {
+            self.defaults = self.selected_distr.get_defaults();
+            self.pars = default_pars(&self.defaults);
+            self.binding = None;
+        }
+
+        ui.add_space(10.0);
+        let bound = self.binding.as_ref().map(|b| b.slot());
+        let (mut bind, mut unbind) = (None, false);
+        for (i, (p, par)) in self.defaults.iter().zip(self.pars.iter_mut()).enumerate() {
+            match p {
+                MixedParam::Unsigned { param: p } => {
+                    ui.add(egui::Label::new(format!("{}:", p.name)));
+                    ui.add(
+                        egui::DragValue::new(par)
+                            .range(p.range.clone())
+                            .speed(p.speed),
+                    )
+                    .on_hover_text(p.desc.clone().unwrap_or("".to_owned()));
+                }
+                MixedParam::Float { param: p } => {
+                    ui.add(egui::Label::new(format!("{}:", p.name)));
+                    if bound == Some(i) {
+                        if let Some(b) = &mut self.binding
+                            && !b.ui(ui)
+                        {
+                            unbind = true;
+                        }
+                        continue;
+                    }
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::DragValue::new(par)
+                                .range(p.range.clone())
+                                .speed(p.speed),
+                        )
+                        .on_hover_text(p.desc.clone().unwrap_or("".to_owned()));
+                        if bound.is_none() && bind_button(ui) {
+                            bind = Some(Binding::new(i, *par, &p.range));
+                        }
+                    });
+                }
+                MixedParam::Signed { param: p } => {
+                    ui.add(egui::Label::new(format!("{}:", p.name)));
+                    ui.add(egui::DragValue::new(par).range(p.range.clone()));
+                }
+                MixedParam::FloatList { .. } | MixedParam::UnsignedList { .. } => {
+                    unreachable!("no continuous distribution takes a list parameter")
+                }
+            }
+        }
+        if unbind {
+            self.binding = None;
+        }
+        if bind.is_some() {
+            self.binding = bind;
+        }
+    }
+
+    /// The distribution with its parameters, or the compound one integrated
+    /// over the parameter that is drawn from a distribution.
+    fn get_distr(&self) -> Result<Box<dyn Cont>, Box<dyn Error>> {
+        let Some(binding) = &self.binding else {
+            return self.build(&self.pars);
+        };
+        let compound = binding.marginal(self.pars.clone(), |mixing| {
+            let family = |t: f64| {
+                let mut pars = self.pars.clone();
+                pars[binding.slot()] = t;
+                self.build(&pars).ok()
+            };
+            Ok(Compound::new(family, mixing)?)
+        })?;
+        Ok(Box::new(compound))
+    }
+
+    fn build(&self, p: &[f64]) -> Result<Box<dyn Cont>, Box<dyn Error>> {
         let res: Box<dyn Cont> = match self.selected_distr {
-            DistrTypes::Normal => Box::new(Normal::new(self.par1, self.par2)?),
-            DistrTypes::Gamma => Box::new(Gamma::new(self.par1, self.par2)?),
-            DistrTypes::Beta => Box::new(Beta::new(self.par1, self.par2)?),
-            DistrTypes::Cauchy => Box::new(Cauchy::new(self.par1, self.par2)?),
-            DistrTypes::ChiSquared => Box::new(ChiSquared::new(self.par1)?),
-            DistrTypes::Exp => Box::new(Exp::new(self.par1)?),
-            DistrTypes::FisherSnedecor => Box::new(FisherSnedecor::new(self.par1, self.par2)?),
-            DistrTypes::Gumbel => Box::new(Gumbel::new(self.par1, self.par2)?),
-            DistrTypes::InverseGamma => Box::new(InverseGamma::new(self.par1, self.par2)?),
-            DistrTypes::Laplace => Box::new(Laplace::new(self.par1, self.par2)?),
-            DistrTypes::LogNormal => Box::new(LogNormal::new(self.par1, self.par2)?),
-            DistrTypes::Pareto => Box::new(Pareto::new(self.par1, self.par2)?),
-            DistrTypes::StudentsT => Box::new(StudentsT::new(self.par1, self.par2, self.par3)?),
-            DistrTypes::Triangular => Box::new(Triangular::new(self.par1, self.par2, self.par3)?),
-            DistrTypes::Uniform => Box::new(Uniform::new(self.par1, self.par2)?),
-            DistrTypes::Weibull => Box::new(Weibull::new(self.par1, self.par2)?),
+            DistrTypes::Normal => Box::new(Normal::new(p[0], p[1])?),
+            DistrTypes::Gamma => Box::new(Gamma::new(p[0], p[1])?),
+            DistrTypes::Beta => Box::new(Beta::new(p[0], p[1])?),
+            DistrTypes::Burr => Box::new(Burr::new(p[0], p[1], p[2])?),
+            DistrTypes::Cauchy => Box::new(Cauchy::new(p[0], p[1])?),
+            DistrTypes::Chi => Box::new(Chi::new(p[0] as u64)?),
+            DistrTypes::ChiSquared => Box::new(ChiSquared::new(p[0])?),
+            DistrTypes::Dagum => Box::new(Dagum::new(p[0], p[1], p[2])?),
+            DistrTypes::Erlang => Box::new(Erlang::new(p[0] as u64, p[1])?),
+            DistrTypes::Exp => Box::new(Exp::new(p[0])?),
+            DistrTypes::FisherSnedecor => Box::new(FisherSnedecor::new(p[0], p[1])?),
+            DistrTypes::FoldedNormal => Box::new(FoldedNormal::new(p[0], p[1])?),
+            DistrTypes::Frechet => Box::new(Gev::frechet(p[0], p[1], p[2])?),
+            DistrTypes::GeneralizedExtremeValue => Box::new(Gev::new(p[0], p[1], p[2])?),
+            DistrTypes::GeneralizedPareto => Box::new(GeneralizedPareto::new(p[0], p[1], p[2])?),
+            DistrTypes::Gumbel => Box::new(Gumbel::new(p[0], p[1])?),
+            DistrTypes::HalfCauchy => Box::new(HalfCauchy::new(p[0])?),
+            DistrTypes::HalfNormal => Box::new(FoldedNormal::half(p[0])?),
+            DistrTypes::InverseGamma => Box::new(InverseGamma::new(p[0], p[1])?),
+            DistrTypes::InverseGaussian => Box::new(InverseGaussian::new(p[0], p[1])?),
+            DistrTypes::Kumaraswamy => Box::new(Kumaraswamy::new(p[0], p[1])?),
+            DistrTypes::Laplace => Box::new(Laplace::new(p[0], p[1])?),
+            DistrTypes::Levy => Box::new(Levy::new(p[0], p[1])?),
+            DistrTypes::Logistic => Box::new(Logistic::new(p[0], p[1])?),
+            DistrTypes::LogLogistic => Box::new(LogLogistic::new(p[0], p[1])?),
+            DistrTypes::LogNormal => Box::new(LogNormal::new(p[0], p[1])?),
+            DistrTypes::NoncentralChiSquared => Box::new(NoncentralChiSquared::new(p[0], p[1])?),
+            DistrTypes::NoncentralFisherSnedecor => {
+                Box::new(NoncentralFisherSnedecor::new(p[0], p[1], p[2])?)
+            }
+            DistrTypes::NoncentralStudentsT => Box::new(NoncentralStudentsT::new(p[0], p[1])?),
+            DistrTypes::Pareto => Box::new(Pareto::new(p[0], p[1])?),
+            DistrTypes::Pert => Box::new(ScaledBeta::pert(p[0], p[2], p[1], p[3])?),
+            DistrTypes::Rayleigh => Box::new(Rayleigh::new(p[0])?),
+            DistrTypes::ReversedWeibull => Box::new(Gev::reversed_weibull(p[0], p[1], p[2])?),
+            DistrTypes::Rice => Box::new(Rice::new(p[0], p[1])?),
+            DistrTypes::ScaledBeta => Box::new(ScaledBeta::new(p[0], p[1], p[2], p[3])?),
+            DistrTypes::SkewNormal => Box::new(SkewNormal::new(p[0], p[1], p[2])?),
+            DistrTypes::SkewT => Box::new(SkewT::new(p[0], p[1], p[2], p[3])?),
+            DistrTypes::Stable => Box::new(Stable::new(p[0], p[1], p[2], p[3])?),
+            DistrTypes::StudentsT => Box::new(StudentsT::new(p[0], p[1], p[2])?),
+            DistrTypes::Triangular => Box::new(Triangular::new(p[0], p[1], p[2])?),
+            DistrTypes::Uniform => Box::new(Uniform::new(p[0], p[1])?),
+            DistrTypes::VonMises => Box::new(VonMises::new(p[0], p[1])?),
+            DistrTypes::Weibull => Box::new(Weibull::new(p[0], p[1])?),
+            DistrTypes::WrappedCauchy => Box::new(WrappedCauchy::new(p[0], p[1])?),
+            DistrTypes::WrappedNormal => Box::new(WrappedNormal::new(p[0], p[1])?),
         };
         Ok(res)
     }
@@ -1,41 +1,419 @@
 use egui::emath::Numeric;
-use std::error::Error;
+use std::{cell::RefCell, error::Error};
 use strum::IntoEnumIterator;
 
 use crate::distr::disc_distr::*;
-use egui_plot::{Bar, BarChart, Plot};
+use egui_plot::{Bar, BarChart, Line, LineStyle, Plot, PlotPoints};
 use statrs::distribution::*;
 
 use crate::distr::*;
 
-use super::show_summary_stats_table;
+use super::cont::{Binding, bind_button};
+use super::{OrderInputs, show_summary_stats_table};
 pub struct DiscPanel {
+    base: DiscSelector,
+    mode: Mode,
+    components: Vec<Component>,
+    // the mixture sums its entropy, it is only redone when a component or
+    // weight changes
+    mixture_cache: RefCell<Option<(Vec<Component>, Mixture)>>,
+    show_components: bool,
+    sum: SumInputs,
+    // the convolution is expensive, it is only redone when its inputs change
+    sum_cache: RefCell<Option<(SumInputs, Convolution)>>,
+    normal_approx: bool,
+    order: OrderInputs,
+    // the order statistic sums its moments, it is only redone when X or the
+    // sample change
+    order_cache: RefCell<Option<((DiscSelector, OrderInputs), OrderStatistic)>>,
+    show_parent: bool,
+}
+
+/// What the panel plots, a single distribution or one built from others.
+#[derive(PartialEq, Clone, Copy)]
+enum Mode {
+    Single,
+    Mixture,
+    Sum,
+    OrderStatistic,
+}
+
+/// A distribution picked from the catalogue together with its parameters.
+#[derive(Clone, PartialEq)]
+struct DiscSelector {
     par1: f64,
     par2: f64,
     par3: f64,
+    list: Vec<f64>,
     selected_distr: DistrTypes,
     defaults: Vec<MixedParam>,
+    binding: Option<Binding<Compound>>,
+    zeros: Zeros,
+    zero_prob: f64,
+}
+
+/// How the probability of zero is changed, if at all.
+#[derive(PartialEq, Clone, Copy)]
+enum Zeros {
+    Unchanged,
+    Inflated,
+    Hurdle,
+}
+
+/// The terms of a sum, either `X + Y` or `n` independent copies of `X`.
+#[derive(Clone, PartialEq)]
+struct SumInputs {
+    x: DiscSelector,
+    y: DiscSelector,
+    iid: bool,
+    n: u64,
+}
+
+/// Most copies of `X` in an iid sum.
+const MAX_COPIES: u64 = 500;
+
+/// Weighted component of a mixture.
+#[derive(Clone, PartialEq)]
+struct Component {
+    selector: DiscSelector,
+    weight: f64,
 }
 
 impl Default for DiscPanel {
     fn default() -> Self {
-        let defaults = DistrTypes::Poisson.get_defaults();
-        let par1 = match defaults[0].clone() {
-            MixedParam::Float { param: p } => p.default,
-            _ => panic!(),
+        // a small and a large count make the bimodal example
+        let mut low = DiscSelector::new(DistrTypes::Poisson);
+        let mut high = DiscSelector::new(DistrTypes::Poisson);
+        low.par1 = 2.0;
+        high.par1 = 10.0;
+        let sum = SumInputs {
+            x: DiscSelector::new(DistrTypes::Geometric),
+            y: DiscSelector::new(DistrTypes::Poisson),
+            iid: false,
+            n: 10,
         };
         Self {
-            par1,
-            par2: 1.0,
-            par3: 1.0,
-            selected_distr: DistrTypes::Poisson,
-            defaults,
+            base: DiscSelector::new(DistrTypes::Poisson),
+            mode: Mode::Single,
+            components: vec![Component::new(low), Component::new(high)],
+            mixture_cache: RefCell::new(None),
+            show_components: true,
+            sum,
+            sum_cache: RefCell::new(None),
+            normal_approx: true,
+            order: OrderInputs::default(),
+            order_cache: RefCell::new(None),
+            show_parent: true,
+        }
+    }
+}
+
+impl Component {
+    fn new(selector: DiscSelector) -> Self {
+        Self {
+            selector,
+            weight: 1.0,
         }
     }
 }
 
 impl DiscPanel {
     pub fn side_panel(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal_wrapped(|ui| {
+            ui.selectable_value(&mut self.mode, Mode::Single, "Single");
+            ui.selectable_value(&mut self.mode, Mode::Mixture, "Mixture");
+            ui.selectable_value(&mut self.mode, Mode::Sum, "Sum");
+            ui.selectable_value(&mut self.mode, Mode::OrderStatistic, "Order statistic");
+        });
+        ui.add_space(10.0);
+        match self.mode {
+            Mode::Single => self.base.ui(ui),
+            Mode::Mixture => self.mixture_ui(ui),
+            Mode::Sum => self.sum_ui(ui),
+            Mode::OrderStatistic => self.order_ui(ui),
+        }
+        ui.add_space(10.0);
+        ui.separator();
+
+        ui.heading("Summary Statistics:");
+        ui.add_space(10.0);
+        // Summary statistics
+        let distr = self.get_distr();
+        if let Ok(d) = distr {
+            let summary = SummaryStats::new(&(*d));
+            show_summary_stats_table(ui, &summary);
+            ui.add_space(10.0);
+        }
+    }
+
+    pub fn central_panel(&mut self, ui: &mut egui::Ui) {
+        let distr = self.get_distr();
+        match distr {
+            Err(e) => {
+                ui.colored_label(
+                    ui.visuals().error_fg_color,
+                    format!("Invalid parameters: {e}"),
+                );
+            }
+            Ok(d) => {
+                // Statrs inverse_cdf is not stable for discrete distributions
+                let (min, max) = get_min_max(d.as_ref());
+                let x = min..=max;
+                let cdf_bars: Vec<Bar> = x
+                    .clone()
+                    .map(|x| Bar::new(x as f64, d.cdf(x)).name("CDF"))
+                    .collect();
+
+                let pmf_bars: Vec<Bar> = x
+                    .clone()
+                    .map(|x| Bar::new(x as f64, d.pmf(x)).name("PMF"))
+                    .collect();
+
+                let cdf_chart = BarChart::new("CDF", cdf_bars);
+                let pmf_chart = BarChart::new("PMF", pmf_bars);
+                let mut overlays = self.component_lines(x.clone());
+                overlays.extend(self.normal_line(d.as_ref(), x.clone()));
+                overlays.extend(self.parent_line(x));
+
+                Plot::new("MainPlot").view_aspect(2.0).show(ui, |plot_ui| {
+                    plot_ui.bar_chart(pmf_chart);
+                    plot_ui.bar_chart(cdf_chart);
+                    for line in overlays {
+                        plot_ui.line(line);
+                    }
+                    plot_ui.set_plot_bounds(egui_plot::PlotBounds::from_min_max(
+                        [min as f64 - 1., 0.0],
+                        [max as f64 + 1., 1.2],
+                    ));
+                    // allow x axis to use auto bounds
+                    plot_ui.set_auto_bounds(egui::Vec2b::new(false, true))
+                });
+            }
+        }
+    }
+}
+
+impl DiscPanel {
+    fn get_distr(&self) -> Result<Box<dyn Disc>, Box<dyn Error>> {
+        match self.mode {
+            Mode::Single => self.base.get_distr(),
+            Mode::Mixture => Ok(Box::new(self.get_mixture()?)),
+            Mode::Sum => Ok(Box::new(self.get_sum()?)),
+            Mode::OrderStatistic => Ok(Box::new(self.get_order_statistic()?)),
+        }
+    }
+
+    /// The cached order statistic if X and the sample are unchanged,
+    /// otherwise a new one, which replaces it.
+    fn get_order_statistic(&self) -> Result<OrderStatistic, Box<dyn Error>> {
+        let inputs = (self.base.clone(), self.order);
+        if let Some((cached, distr)) = &*self.order_cache.borrow()
+            && *cached == inputs
+        {
+            return Ok(distr.clone());
+        }
+        let distr = OrderStatistic::new(self.base.get_distr()?, self.order.n, self.order.k)?;
+        *self.order_cache.borrow_mut() = Some((inputs, distr.clone()));
+        Ok(distr)
+    }
+
+    /// The cached mixture if its components are unchanged, otherwise a new
+    /// one, which replaces it.
+    fn get_mixture(&self) -> Result<Mixture, Box<dyn Error>> {
+        if let Some((cached, distr)) = &*self.mixture_cache.borrow()
+            && *cached == self.components
+        {
+            return Ok(distr.clone());
+        }
+        let components = self
+            .components
+            .iter()
+            .map(|c| Ok((c.weight, c.selector.get_distr()?)))
+            .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+        let distr = Mixture::new(components)?;
+        *self.mixture_cache.borrow_mut() = Some((self.components.clone(), distr.clone()));
+        Ok(distr)
+    }
+
+    fn get_sum(&self) -> Result<Convolution, Box<dyn Error>> {
+        if let Some((inputs, distr)) = &*self.sum_cache.borrow()
+            && *inputs == self.sum
+        {
+            return Ok(distr.clone());
+        }
+        let x = self.sum.x.get_distr()?;
+        let distr = if self.sum.iid {
+            Convolution::new(&[(x.as_ref(), self.sum.n)])?
+        } else {
+            let y = self.sum.y.get_distr()?;
+            Convolution::new(&[(x.as_ref(), 1), (y.as_ref(), 1)])?
+        };
+        *self.sum_cache.borrow_mut() = Some((self.sum.clone(), distr.clone()));
+        Ok(distr)
+    }
+
+    fn mixture_ui(&mut self, ui: &mut egui::Ui) {
+        let mut remove = None;
+        for (i, c) in self.components.iter_mut().enumerate() {
+            ui.push_id(i, |ui| {
+                ui.horizontal(|ui| {
+                    ui.strong(format!("Component {}", i + 1));
+                    if ui.small_button("🗙").on_hover_text("Remove").clicked() {
+                        remove = Some(i);
+                    }
+                });
+                c.selector.ui(ui);
+                ui.add(egui::Label::new("Weight:"));
+                ui.add(
+                    egui::DragValue::new(&mut c.weight)
+                        .range(0.0..=f64::MAX)
+                        .speed(0.05),
+                )
+                .on_hover_text(">=0, normalized to sum to 1");
+                ui.add_space(10.0);
+            });
+        }
+        // keep at least one component so the mixture stays defined
+        if let Some(i) = remove
+            && self.components.len() > 1
+        {
+            self.components.remove(i);
+        }
+        if ui.button("➕ Add component").clicked() {
+            self.components
+                .push(Component::new(DiscSelector::new(DistrTypes::Poisson)));
+        }
+        ui.checkbox(&mut self.show_components, "Show components");
+    }
+
+    /// Dashed lines through the weighted PMFs of the mixture components, if
+    /// they are shown.
+    fn component_lines(&self, x: impl Iterator<Item = i64> + Clone) -> Vec<Line<'_>> {
+        if self.mode != Mode::Mixture || !self.show_components {
+            return Vec::new();
+        }
+        let Ok(mixture) = self.get_mixture() else {
+            return Vec::new();
+        };
+        mixture
+            .components()
+            .enumerate()
+            .map(|(i, (w, c))| {
+                let points: PlotPoints<'_> = x.clone().map(|x| [x as f64, w * c.pmf(x)]).collect();
+                Line::new("Default", points)
+                    .name(format!("Component {}", i + 1))
+                    .style(LineStyle::dashed_loose())
+            })
+            .collect()
+    }
+
+    fn sum_ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.sum.iid, false, "X + Y");
+            ui.selectable_value(&mut self.sum.iid, true, "X₁ + … + Xₙ");
+        });
+        ui.add_space(10.0);
+        ui.strong("X");
+        ui.push_id("x", |ui| self.sum.x.ui(ui));
+        ui.add_space(10.0);
+        if self.sum.iid {
+            ui.add(egui::Label::new("n:"));
+            ui.add(egui::DragValue::new(&mut self.sum.n).range(1..=MAX_COPIES))
+                .on_hover_text("Number of independent copies of X");
+        } else {
+            ui.strong("Y");
+            ui.push_id("y", |ui| self.sum.y.ui(ui));
+        }
+        ui.checkbox(&mut self.normal_approx, "Show normal approximation");
+    }
+
+    /// Dashed line through the normal density with the same mean and variance
+    /// as the sum, if it is shown.
+    fn normal_line(&self, d: &dyn Disc, x: impl Iterator<Item = i64>) -> Option<Line<'_>> {
+        if self.mode != Mode::Sum || !self.normal_approx {
+            return None;
+        }
+        let normal = Normal::new(d.mean()?, d.std_dev()?).ok()?;
+        let points: PlotPoints<'_> = x.map(|x| [x as f64, normal.pdf(x as f64)]).collect();
+        Some(
+            Line::new("Default", points)
+                .name("Normal approximation")
+                .style(LineStyle::dashed_loose()),
+        )
+    }
+
+    fn order_ui(&mut self, ui: &mut egui::Ui) {
+        self.base.ui(ui);
+        ui.add_space(10.0);
+        self.order.ui(ui);
+        ui.checkbox(&mut self.show_parent, "Show single draw");
+    }
+
+    /// Dashed line through the PMF of a single draw from the sample, if it is
+    /// shown.
+    fn parent_line(&self, x: impl Iterator<Item = i64>) -> Option<Line<'_>> {
+        if self.mode != Mode::OrderStatistic || !self.show_parent {
+            return None;
+        }
+        let parent = self.base.get_distr().ok()?;
+        let points: PlotPoints<'_> = x.map(|x| [x as f64, parent.pmf(x)]).collect();
+        Some(
+            Line::new("Default", points)
+                .name("Single draw")
+                .style(LineStyle::dashed_loose()),
+        )
+    }
+}
+
+impl DiscSelector {
+    fn new(selected_distr: DistrTypes) -> Self {
+        let mut selector = Self {
+            par1: 1.0,
+            par2: 1.0,
+            par3: 1.0,
+            list: Vec::new(),
+            selected_distr,
+            defaults: Vec::new(),
+            binding: None,
+            zeros: Zeros::Unchanged,
+            zero_prob: 0.3,
+        };
+        selector.reset();
+        selector
+    }
+
+    /// Loads the default parameters of the selected distribution.
+    fn reset(&mut self) {
+        self.defaults = self.selected_distr.get_defaults();
+        self.binding = None;
+        for (i, p) in self.defaults.iter().enumerate() {
+            let par = match p {
+                MixedParam::Signed { param: p } => p.default.to_f64(),
+                MixedParam::Unsigned { param: p } => p.default.to_f64(),
+                MixedParam::Float { param: p } => p.default,
+                MixedParam::FloatList { default, .. } => {
+                    self.list = default.clone();
+                    continue;
+                }
+                MixedParam::UnsignedList { default, .. } => {
+                    self.list = default.iter().map(|&c| c.to_f64()).collect();
+                    continue;
+                }
+            };
+            if i == 0 {
+                self.par1 = par
+            } else if i == 1 {
+                self.par2 = par
+            } else if i == 2 {
+                self.par3 = par
+            } else {
+                panic!()
+            }
+        }
+    }
+
+    /// Distribution picker and the inputs for its parameters.
+    fn ui(&mut self, ui: &mut egui::Ui) {
         let mut reset = false;
         egui::ComboBox::from_label("Select a distribution")
             .selected_text(format!("{}", self.selected_distr))
@@ -52,26 +430,12 @@ impl DiscPanel {
             });
 
         if reset {
-            self.defaults = self.selected_distr.get_defaults();
-            for (i, p) in self.defaults.iter().enumerate() {
-                let par = match p {
-                    MixedParam::_Signed { param: p } => p.default.to_f64(),
-                    MixedParam::Unsigned { param: p } => p.default.to_f64(),
-                    MixedParam::Float { param: p } => p.default,
-                };
-                if i == 0 {
-                    self.par1 = par
-                } else if i == 1 {
-                    self.par2 = par
-                } else if i == 2 {
-                    self.par3 = par
-                } else {
-                    panic!()
-                }
-            }
+            self.reset();
         }
 
         ui.add_space(10.0);
+        let bound = self.binding.as_ref().map(|b| b.slot());
+        let (mut bind, mut unbind) = (None, false);
         for (i, p) in self.defaults.clone().into_iter().enumerate() {
             match p {
                 MixedParam::Unsigned { param: p } => {
@@ -91,143 +455,200 @@ impl DiscPanel {
                 }
                 MixedParam::Float { param: p } => {
                     ui.add(egui::Label::new(format!("{}:", p.name)));
-                    if p.name == "p" {
-                        // probability parameter
-                        if i == 0 {
-                            ui.add(
-                                egui::Slider::new(&mut self.par1, p.range)
-                                    .drag_value_speed(p.speed),
-                            )
-                            .on_hover_text(p.desc.unwrap_or("".to_owned()));
-                        } else if i == 1 {
+                    if bound == Some(i) {
+                        if let Some(b) = &mut self.binding
+                            && !b.ui(ui)
+                        {
+                            unbind = true;
+                        }
+                        continue;
+                    }
+                    let par = match i {
+                        0 => &mut self.par1,
+                        1 => &mut self.par2,
+                        2 => &mut self.par3,
+                        _ => panic!(),
+                    };
+                    ui.horizontal(|ui| {
+                        if p.name == "p" {
+                            // probability parameter
                             ui.add(
-                                egui::Slider::new(&mut self.par2, p.range)
-                                    .drag_value_speed(p.speed),
+                                egui::Slider::new(par, p.range.clone()).drag_value_speed(p.speed),
                             )
-                            .on_hover_text(p.desc.unwrap_or("".to_owned()));
-                        } else if i == 2 {
+                        } else {
                             ui.add(
-                                egui::Slider::new(&mut self.par3, p.range)
-                                    .drag_value_speed(p.speed),
+                                egui::DragValue::new(par)
+                                    .range(p.range.clone())
+                                    .speed(p.speed),
                             )
-                            .on_hover_text(p.desc.unwrap_or("".to_owned()));
-                        } else {
-                            panic!()
                         }
-                    } else if i == 0 {
-                        ui.add(
-                            egui::DragValue::new(&mut self.par1)
-                                .range(p.range)
-                                .speed(p.speed),
-                        )
-                        .on_hover_text(p.desc.unwrap_or("".to_owned()));
-                    } else if i == 1 {
-                        ui.add(
-                            egui::DragValue::new(&mut self.par2)
-                                .range(p.range)
-                                .speed(p.speed),
-                        )
-                        .on_hover_text(p.desc.unwrap_or("".to_owned()));
-                    } else if i == 2 {
-                        ui.add(
-                            egui::DragValue::new(&mut self.par3)
-                                .range(p.range)
-                                .speed(p.speed),
-                        )
-                        .on_hover_text(p.desc.unwrap_or("".to_owned()));
-                    } else {
-                        panic!()
-                    }
+                        .on_hover_text(p.desc.clone().unwrap_or("".to_owned()));
+                        if bound.is_none() && bind_button(ui) {
+                            bind = Some(Binding::new(i, *par, &p.range));
+                        }
+                    });
                 }
-                MixedParam::_Signed { param: p } => {
+                MixedParam::Signed { param: p } => {
                     ui.add(egui::Label::new(format!("{}:", p.name)));
                     if i == 0 {
-                        ui.add(egui::DragValue::new(&mut self.par1).range(p.range));
+                        ui.add(egui::DragValue::new(&mut self.par1).range(p.range))
+                            .on_hover_text(p.desc.unwrap_or("".to_owned()));
                     } else if i == 1 {
-                        ui.add(egui::DragValue::new(&mut self.par2).range(p.range));
+                        ui.add(egui::DragValue::new(&mut self.par2).range(p.range))
+                            .on_hover_text(p.desc.unwrap_or("".to_owned()));
                     } else if i == 2 {
-                        ui.add(egui::DragValue::new(&mut self.par3).range(p.range));
+                        ui.add(egui::DragValue::new(&mut self.par3).range(p.range))
+                            .on_hover_text(p.desc.unwrap_or("".to_owned()));
                     } else {
                         panic!()
                     }
                 }
+                MixedParam::FloatList { param: p, .. } => {
+                    ui.add(egui::Label::new(format!("{}:", p.name)));
+                    list_param_ui(ui, &mut self.list, &p);
+                }
+                MixedParam::UnsignedList { param: p, .. } => {
+                    ui.add(egui::Label::new(format!("{}:", p.name)));
+                    list_param_ui(ui, &mut self.list, &p);
+                }
             }
         }
-        ui.add_space(10.0);
-        ui.separator();
-
-        ui.heading("Summary Statistics:");
-        ui.add_space(10.0);
-        // Summary statistics
-        let distr = self.get_distr();
-        if let Ok(d) = distr {
-            let summary = SummaryStats::new(&(*d));
-            show_summary_stats_table(ui, &summary);
-            ui.add_space(10.0);
+        if unbind {
+            self.binding = None;
+        }
+        if bind.is_some() {
+            self.binding = bind;
         }
-    }
-
-    pub fn central_panel(&mut self, ui: &mut egui::Ui) {
-        let distr = self.get_distr();
-        match distr {
-            Err(_) => {}
-            Ok(d) => {
-                // Statrs inverse_cdf is not stable for discrete distributions
-                let (min, max) = get_min_max(&d);
-                let x = min..=max;
-                let cdf_bars: Vec<Bar> = x
-                    .clone()
-                    .map(|x| Bar::new(x as f64, d.cdf(x)).name("CDF"))
-                    .collect();
-
-                let pmf_bars: Vec<Bar> = x
-                    .map(|x| Bar::new(x as f64, d.pmf(x)).name("PMF"))
-                    .collect();
-
-                let cdf_chart = BarChart::new("CDF", cdf_bars);
-                let pmf_chart = BarChart::new("PMF", pmf_bars);
 
-                Plot::new("MainPlot").view_aspect(2.0).show(ui, |plot_ui| {
-                    plot_ui.bar_chart(pmf_chart);
-                    plot_ui.bar_chart(cdf_chart);
-                    plot_ui.set_plot_bounds(egui_plot::PlotBounds::from_min_max(
-                        [min as f64 - 1., 0.0],
-                        [max as f64 + 1., 1.2],
-                    ));
-                    // allow x axis to use auto bounds
-                    plot_ui.set_auto_bounds(egui::Vec2b::new(false, true))
-                });
+        ui.add_space(5.0);
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Zeros:");
+            ui.selectable_value(&mut self.zeros, Zeros::Unchanged, "Unchanged");
+            ui.selectable_value(&mut self.zeros, Zeros::Inflated, "Zero-inflated");
+            ui.selectable_value(&mut self.zeros, Zeros::Hurdle, "Hurdle");
+        });
+        let desc = match self.zeros {
+            Zeros::Unchanged => return,
+            Zeros::Inflated => "Probability of an extra, structural zero",
+            Zeros::Hurdle => {
+                "Probability of zero, other values follow the distribution conditioned on not being zero"
             }
-        }
+        };
+        ui.add(egui::Slider::new(&mut self.zero_prob, 0.0..=1.0).text("π"))
+            .on_hover_text(desc);
     }
-}
 
-impl DiscPanel {
+    /// The distribution with its probability of zero changed as selected.
     fn get_distr(&self) -> Result<Box<dyn Disc>, Box<dyn Error>> {
+        let distr = self.get_unmodified()?;
+        Ok(match self.zeros {
+            Zeros::Unchanged => distr,
+            Zeros::Inflated => Box::new(ZeroModified::inflated(distr, self.zero_prob)?),
+            Zeros::Hurdle => Box::new(ZeroModified::hurdle(distr, self.zero_prob)?),
+        })
+    }
+
+    /// The distribution with its parameters, or the compound one summed
+    /// over the parameter that is drawn from a distribution.
+    fn get_unmodified(&self) -> Result<Box<dyn Disc>, Box<dyn Error>> {
+        let pars = [self.par1, self.par2, self.par3];
+        let Some(binding) = &self.binding else {
+            return self.build(pars);
+        };
+        let mut key = pars.to_vec();
+        key.extend(&self.list);
+        let compound = binding.marginal(key, |mixing| {
+            let family = |t: f64| {
+                let mut pars = pars;
+                pars[binding.slot()] = t;
+                self.build(pars).ok()
+            };
+            Ok(Compound::new(family, mixing)?)
+        })?;
+        Ok(Box::new(compound))
+    }
+
+    fn build(&self, p: [f64; 3]) -> Result<Box<dyn Disc>, Box<dyn Error>> {
         let res: Box<dyn Disc> = match self.selected_distr {
-            DistrTypes::Poisson => Box::new(Poisson::new(self.par1)?),
-            DistrTypes::Binomial => Box::new(Binomial::new(self.par1, self.par2 as u64)?),
-            DistrTypes::Bernoulli => Box::new(Bernoulli::new(self.par1)?),
-            DistrTypes::Geometric => Box::new(Geometric::new(self.par1)?),
-            DistrTypes::Hypergeometric => Box::new(Hypergeometric::new(
-                self.par1 as u64,
-                self.par2 as u64,
-                self.par3 as u64,
-            )?),
+            DistrTypes::Poisson => Box::new(NonNegative(Poisson::new(p[0])?)),
+            DistrTypes::Binomial => Box::new(NonNegative(Binomial::new(p[0], p[1] as u64)?)),
+            DistrTypes::Bernoulli => Box::new(NonNegative(Bernoulli::new(p[0])?)),
+            DistrTypes::Geometric => Box::new(NonNegative(Geometric::new(p[0])?)),
+            DistrTypes::Hypergeometric => Box::new(NonNegative(Hypergeometric::new(
+                p[0] as u64,
+                p[1] as u64,
+                p[2] as u64,
+            )?)),
+            DistrTypes::NegativeBinomial => Box::new(NonNegative(NegBinomial::new(p[0], p[1])?)),
+            DistrTypes::DiscreteUniform => {
+                Box::new(DiscreteUniform::new(p[0] as i64, p[1] as i64)?)
+            }
+            DistrTypes::Categorical => Box::new(NonNegative(Categorical::new(&self.list)?)),
+            DistrTypes::BetaBinomial => {
+                Box::new(NonNegative(BetaBinomial::new(p[0] as u64, p[1], p[2])?))
+            }
+            DistrTypes::Zipf => Box::new(NonNegative(Zipf::new(p[0], p[1] as u64)?)),
+            DistrTypes::Zeta => Box::new(NonNegative(Zipf::zeta(p[0])?)),
+            DistrTypes::Logarithmic => Box::new(NonNegative(Logarithmic::new(p[0])?)),
+            DistrTypes::YuleSimon => Box::new(NonNegative(YuleSimon::new(p[0])?)),
+            DistrTypes::Skellam => Box::new(Skellam::new(p[0], p[1])?),
+            DistrTypes::DiscreteLaplace => Box::new(DiscreteLaplace::new(p[0] as i64, p[1])?),
+            DistrTypes::PoissonBinomial => Box::new(NonNegative(PoissonBinomial::new(&self.list)?)),
+            DistrTypes::NegativeHypergeometric => Box::new(NonNegative(
+                NegativeHypergeometric::new(p[0] as u64, p[1] as u64, p[2] as u64)?,
+            )),
+            DistrTypes::MultivariateHypergeometric => {
+                let counts: Vec<u64> = self.list.iter().map(|&c| c.round() as u64).collect();
+                let distr = MultivariateHypergeometric::new(&counts, p[1] as u64)?;
+                Box::new(NonNegative(distr.marginal(p[2] as usize)?))
+            }
         };
         Ok(res)
     }
 }
 
-fn get_min_max(distr: &Box<dyn Disc>) -> (u64, u64) {
-    let mut min = 0;
-    while distr.cdf(min) < 0.001 {
-        min += 1
+/// Most bars drawn at once, long-tailed distributions such as Zipf or
+/// Yule-Simon only reach the 0.999 quantile millions of values out.
+const MAX_BARS: i64 = 1000;
+
+fn get_min_max(distr: &dyn Disc) -> (i64, i64) {
+    let min = quantile(distr, 0.001).saturating_sub(1);
+    let max = quantile(distr, 0.999)
+        .max(min + 1)
+        .min(min.saturating_add(MAX_BARS));
+    (min, max)
+}
+
+/// Editable list of values, whole numbers only for an integer `T`.
+fn list_param_ui<T: Numeric>(ui: &mut egui::Ui, values: &mut Vec<f64>, param: &Param<T>) {
+    let mut remove = None;
+    for (i, v) in values.iter_mut().enumerate() {
+        ui.horizontal(|ui| {
+            ui.label(format!("{}:", i));
+            let mut drag = egui::DragValue::new(v)
+                .range(param.range.clone())
+                .speed(param.speed);
+            if T::INTEGRAL {
+                drag = drag.max_decimals(0);
+            }
+            ui.add(drag)
+                .on_hover_text(param.desc.clone().unwrap_or("".to_owned()));
+            if T::INTEGRAL {
+                // typed in values may still have a fraction
+                *v = v.round();
+            }
+            if ui.small_button("🗙").on_hover_text("Remove").clicked() {
+                remove = Some(i);
+            }
+        });
     }
-    min = min.saturating_sub(1);
-    let mut max = min + 1;
-    while distr.cdf(max) < 0.999 && max < u64::MAX {
-        max += 1;
+    // keep at least one entry so the distribution stays defined
+    if let Some(i) = remove
+        && values.len() > 1
+    {
+        values.remove(i);
+    }
+    if ui.button("➕ Add").clicked() {
+        values.push(param.default.to_f64());
     }
-    (min, max)
 }
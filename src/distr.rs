@@ -1,16 +1,36 @@
+use cont_distr::Circular;
 use statrs::statistics::Distribution;
 use std::ops::RangeInclusive;
 
 pub mod cont_distr;
 pub mod disc_distr;
+pub mod expr;
+mod numeric;
 
 pub const BARELY_POSITIVE: f64 = 0.001;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum MixedParam {
-    Float { param: Param<f64> },
-    Unsigned { param: Param<u64> },
-    _Signed { param: Param<i64> },
+    Float {
+        param: Param<f64>,
+    },
+    Unsigned {
+        param: Param<u64>,
+    },
+    Signed {
+        param: Param<i64>,
+    },
+    // Variable-length list of floats, `param` describes a single entry and
+    // its `default` is the value of newly added entries.
+    FloatList {
+        param: Param<f64>,
+        default: Vec<f64>,
+    },
+    // Variable-length list of counts, as `FloatList` with whole entries.
+    UnsignedList {
+        param: Param<u64>,
+        default: Vec<u64>,
+    },
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -29,6 +49,9 @@ pub struct SummaryStats {
     pub std_dev: Option<f64>,
     pub entropy: Option<f64>,
     pub skewness: Option<f64>,
+    pub mean_direction: Option<f64>,
+    pub mean_resultant_length: Option<f64>,
+    pub circular_variance: Option<f64>,
 }
 
 impl SummaryStats {
@@ -39,36 +62,52 @@ impl SummaryStats {
             std_dev: distr.std_dev(),
             entropy: distr.entropy(),
             skewness: distr.skewness(),
+            mean_direction: None,
+            mean_resultant_length: None,
+            circular_variance: None,
         }
     }
-    pub fn display_mean(&self) -> String {
-        match self.mean {
-            None => "N/A".to_owned(),
-            Some(f) => format!("{:.3}", f),
-        }
-    }
-    pub fn display_variance(&self) -> String {
-        match self.variance {
-            None => "N/A".to_owned(),
-            Some(f) => format!("{:.3}", f),
+    pub fn circular(distr: &dyn Circular) -> SummaryStats {
+        let r = distr.mean_resultant_length();
+        SummaryStats {
+            mean: None,
+            variance: None,
+            std_dev: None,
+            entropy: distr.entropy(),
+            skewness: None,
+            mean_direction: Some(distr.mean_direction()),
+            mean_resultant_length: Some(r),
+            circular_variance: Some(1.0 - r),
         }
     }
-    pub fn display_std_dev(&self) -> String {
-        match self.std_dev {
-            None => "N/A".to_owned(),
-            Some(f) => format!("{:.3}", f),
-        }
+    pub fn is_circular(&self) -> bool {
+        self.mean_direction.is_some()
     }
-    pub fn display_entropy(&self) -> String {
-        match self.entropy {
-            None => "N/A".to_owned(),
-            Some(f) => format!("{:.3}", f),
+    /// Label and formatted value of every statistic to show, circular
+    /// statistics replace the linear moments where they apply.
+    pub fn rows(&self) -> Vec<(&'static str, String)> {
+        if self.is_circular() {
+            vec![
+                ("Mean dir.", display(self.mean_direction)),
+                ("Resultant len.", display(self.mean_resultant_length)),
+                ("Circ. variance", display(self.circular_variance)),
+                ("Entropy", display(self.entropy)),
+            ]
+        } else {
+            vec![
+                ("Mean", display(self.mean)),
+                ("Variance", display(self.variance)),
+                ("Std. Dev.", display(self.std_dev)),
+                ("Entropy", display(self.entropy)),
+                ("Skewness", display(self.skewness)),
+            ]
         }
     }
-    pub fn display_skewness(&self) -> String {
-        match self.skewness {
-            None => "N/A".to_owned(),
-            Some(f) => format!("{:.3}", f),
-        }
+}
+
+fn display(stat: Option<f64>) -> String {
+    match stat {
+        None => "N/A".to_owned(),
+        Some(f) => format!("{:.3}", f),
     }
 }
@@ -1,26 +1,100 @@
-use super::{BARELY_POSITIVE, MixedParam, Param};
+use super::{
+    BARELY_POSITIVE, MixedParam, Param,
+    numeric::{QUANTILE_LEVELS, integrate_pieces},
+};
 use statrs::{distribution::*, statistics::Distribution};
-use std::{fmt::Display, ops::RangeInclusive};
+use std::{f64::consts::PI, fmt::Display, ops::RangeInclusive};
 use strum_macros::EnumIter;
 
+mod bounded;
+mod burr;
+mod circular;
+mod compound;
+mod convolution;
+mod extreme_value;
+mod extremum;
+mod first_passage;
+mod folded;
+mod logistic;
+mod mixture;
+mod noncentral;
+mod order_statistic;
+mod product;
+mod rice;
+mod skewed;
+mod stable;
+mod table;
+mod transformed;
+mod truncated;
+pub use bounded::*;
+pub use burr::*;
+pub use circular::*;
+pub use compound::*;
+pub use convolution::*;
+pub use extreme_value::*;
+pub use extremum::*;
+pub use first_passage::*;
+pub use folded::*;
+pub use logistic::*;
+pub use mixture::*;
+pub use noncentral::*;
+pub use order_statistic::*;
+pub use product::*;
+pub use rice::*;
+pub use skewed::*;
+pub use stable::*;
+pub use transformed::*;
+pub use truncated::*;
+
+use table::Table;
+
 #[derive(Debug, PartialEq, PartialOrd, EnumIter, Clone)]
 pub enum DistrTypes {
     Normal,
     Gamma,
     Beta,
+    Burr,
     Cauchy,
+    Chi,
     ChiSquared,
+    Dagum,
+    Erlang,
     Exp,
     FisherSnedecor,
+    FoldedNormal,
+    Frechet,
+    GeneralizedExtremeValue,
+    GeneralizedPareto,
     Gumbel,
+    HalfCauchy,
+    HalfNormal,
     InverseGamma,
+    InverseGaussian,
+    Kumaraswamy,
     Laplace,
+    Levy,
+    Logistic,
+    LogLogistic,
     LogNormal,
+    NoncentralChiSquared,
+    NoncentralFisherSnedecor,
+    NoncentralStudentsT,
     Pareto,
+    Pert,
+    Rayleigh,
+    ReversedWeibull,
+    Rice,
+    ScaledBeta,
+    SkewNormal,
+    SkewT,
+    Stable,
     StudentsT,
     Triangular,
     Uniform,
+    VonMises,
     Weibull,
+    WrappedCauchy,
+    WrappedNormal,
 }
 
 impl Display for DistrTypes {
@@ -29,19 +103,48 @@ impl Display for DistrTypes {
             DistrTypes::Normal => write!(f, "Normal"),
             DistrTypes::Gamma => write!(f, "Gamma"),
             DistrTypes::Beta => write!(f, "Beta"),
+            DistrTypes::Burr => write!(f, "Burr"),
             DistrTypes::Cauchy => write!(f, "Cauchy"),
+            DistrTypes::Chi => write!(f, "Chi"),
             DistrTypes::ChiSquared => write!(f, "ChiSquared"),
+            DistrTypes::Dagum => write!(f, "Dagum"),
+            DistrTypes::Erlang => write!(f, "Erlang"),
             DistrTypes::Exp => write!(f, "Exp"),
             DistrTypes::FisherSnedecor => write!(f, "FisherSnedecor"),
+            DistrTypes::FoldedNormal => write!(f, "FoldedNormal"),
+            DistrTypes::Frechet => write!(f, "Frechet"),
+            DistrTypes::GeneralizedExtremeValue => write!(f, "GeneralizedExtremeValue"),
+            DistrTypes::GeneralizedPareto => write!(f, "GeneralizedPareto"),
             DistrTypes::Gumbel => write!(f, "Gumbel"),
+            DistrTypes::HalfCauchy => write!(f, "HalfCauchy"),
+            DistrTypes::HalfNormal => write!(f, "HalfNormal"),
             DistrTypes::InverseGamma => write!(f, "InverseGamma"),
+            DistrTypes::InverseGaussian => write!(f, "InverseGaussian"),
+            DistrTypes::Kumaraswamy => write!(f, "Kumaraswamy"),
             DistrTypes::Laplace => write!(f, "Laplace"),
+            DistrTypes::Levy => write!(f, "Levy"),
+            DistrTypes::Logistic => write!(f, "Logistic"),
+            DistrTypes::LogLogistic => write!(f, "LogLogistic"),
             DistrTypes::LogNormal => write!(f, "LogNormal"),
+            DistrTypes::NoncentralChiSquared => write!(f, "NoncentralChiSquared"),
+            DistrTypes::NoncentralFisherSnedecor => write!(f, "NoncentralFisherSnedecor"),
+            DistrTypes::NoncentralStudentsT => write!(f, "NoncentralStudentsT"),
             DistrTypes::Pareto => write!(f, "Pareto"),
+            DistrTypes::Pert => write!(f, "Pert"),
+            DistrTypes::Rayleigh => write!(f, "Rayleigh"),
+            DistrTypes::ReversedWeibull => write!(f, "ReversedWeibull"),
+            DistrTypes::Rice => write!(f, "Rice"),
+            DistrTypes::ScaledBeta => write!(f, "ScaledBeta"),
+            DistrTypes::SkewNormal => write!(f, "SkewNormal"),
+            DistrTypes::SkewT => write!(f, "SkewT"),
+            DistrTypes::Stable => write!(f, "Stable"),
             DistrTypes::StudentsT => write!(f, "StudentsT"),
             DistrTypes::Triangular => write!(f, "Triangular"),
             DistrTypes::Uniform => write!(f, "Uniform"),
+            DistrTypes::VonMises => write!(f, "VonMises"),
             DistrTypes::Weibull => write!(f, "Weibull"),
+            DistrTypes::WrappedCauchy => write!(f, "WrappedCauchy"),
+            DistrTypes::WrappedNormal => write!(f, "WrappedNormal"),
         }
     }
 }
@@ -109,6 +212,35 @@ impl DistrTypes {
                     },
                 },
             ],
+            DistrTypes::Burr => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 2_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Shape C".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 3_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Shape K".to_owned(),
+                        desc: Some(">0, moment r exists for r < C K".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Scale".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
             DistrTypes::Cauchy => vec![
                 MixedParam::Float {
                     param: Param {
@@ -129,6 +261,15 @@ impl DistrTypes {
                     },
                 },
             ],
+            DistrTypes::Chi => vec![MixedParam::Unsigned {
+                param: Param {
+                    default: 3,
+                    range: RangeInclusive::new(1, u64::MAX),
+                    name: "Freedom".to_owned(),
+                    desc: Some(">0".to_owned()),
+                    speed: 1.0,
+                },
+            }],
             DistrTypes::ChiSquared => vec![MixedParam::Float {
                 param: Param {
                     default: 3_f64,
@@ -138,6 +279,55 @@ impl DistrTypes {
                     speed: 1.0,
                 },
             }],
+            DistrTypes::Dagum => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 4_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Shape A".to_owned(),
+                        desc: Some(">0, moment r exists for r < A".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Scale B".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Shape P".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::Erlang => vec![
+                MixedParam::Unsigned {
+                    param: Param {
+                        default: 2,
+                        range: RangeInclusive::new(1, u64::MAX),
+                        name: "Shape".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 1.0,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Rate".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
             DistrTypes::Exp => vec![MixedParam::Float {
                 param: Param {
                     default: 1_f64,
@@ -167,54 +357,63 @@ impl DistrTypes {
                     },
                 },
             ],
-            DistrTypes::Gumbel => vec![
+            DistrTypes::FoldedNormal => vec![
                 MixedParam::Float {
                     param: Param {
-                        default: 0_f64,
+                        default: 1_f64,
                         range: RangeInclusive::new(f64::MIN, f64::MAX),
-                        name: "Location".to_owned(),
-                        desc: None,
-                        speed: 1.0,
+                        name: "Mean".to_owned(),
+                        desc: Some("Of the normal before folding".to_owned()),
+                        speed: 0.1,
                     },
                 },
                 MixedParam::Float {
                     param: Param {
                         default: 1_f64,
                         range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
-                        name: "Scale".to_owned(),
+                        name: "Std. dev.".to_owned(),
                         desc: Some(">0".to_owned()),
                         speed: 0.1,
                     },
                 },
             ],
-            DistrTypes::InverseGamma => vec![
+            DistrTypes::Frechet => vec![
                 MixedParam::Float {
                     param: Param {
-                        default: 2_f64,
+                        default: 3_f64,
                         range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
                         name: "Shape".to_owned(),
-                        desc: Some(">0".to_owned()),
+                        desc: Some(">0, GEV with ξ = 1/Shape".to_owned()),
                         speed: 0.1,
                     },
                 },
                 MixedParam::Float {
                     param: Param {
-                        default: 2_f64,
+                        default: 1_f64,
                         range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
-                        name: "Rate".to_owned(),
+                        name: "Scale".to_owned(),
                         desc: Some(">0".to_owned()),
                         speed: 0.1,
                     },
                 },
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Min".to_owned(),
+                        desc: Some("Lower bound of the support".to_owned()),
+                        speed: 0.1,
+                    },
+                },
             ],
-            DistrTypes::Laplace => vec![
+            DistrTypes::GeneralizedExtremeValue => vec![
                 MixedParam::Float {
                     param: Param {
                         default: 0_f64,
                         range: RangeInclusive::new(f64::MIN, f64::MAX),
                         name: "Location".to_owned(),
                         desc: None,
-                        speed: 1.,
+                        speed: 1.0,
                     },
                 },
                 MixedParam::Float {
@@ -226,8 +425,17 @@ impl DistrTypes {
                         speed: 0.1,
                     },
                 },
+                MixedParam::Float {
+                    param: Param {
+                        default: 0.1,
+                        range: RangeInclusive::new(-5., 5.),
+                        name: "Shape ξ".to_owned(),
+                        desc: Some("ξ>0 Fréchet, ξ=0 Gumbel, ξ<0 reversed Weibull type".to_owned()),
+                        speed: 0.01,
+                    },
+                },
             ],
-            DistrTypes::LogNormal => vec![
+            DistrTypes::GeneralizedPareto => vec![
                 MixedParam::Float {
                     param: Param {
                         default: 0_f64,
@@ -239,15 +447,33 @@ impl DistrTypes {
                 },
                 MixedParam::Float {
                     param: Param {
-                        default: 0.5,
-                        range: RangeInclusive::new(BARELY_POSITIVE, 3.),
+                        default: 1_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
                         name: "Scale".to_owned(),
                         desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 0.2,
+                        range: RangeInclusive::new(-5., 5.),
+                        name: "Shape ξ".to_owned(),
+                        desc: Some("ξ=0 exponential, ξ<0 bounded above".to_owned()),
                         speed: 0.01,
                     },
                 },
             ],
-            DistrTypes::Pareto => vec![
+            DistrTypes::Gumbel => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Location".to_owned(),
+                        desc: None,
+                        speed: 1.0,
+                    },
+                },
                 MixedParam::Float {
                     param: Param {
                         default: 1_f64,
@@ -257,131 +483,783 @@ impl DistrTypes {
                         speed: 0.1,
                     },
                 },
+            ],
+            DistrTypes::HalfCauchy => vec![MixedParam::Float {
+                param: Param {
+                    default: 1_f64,
+                    range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                    name: "Scale".to_owned(),
+                    desc: Some(">0".to_owned()),
+                    speed: 0.1,
+                },
+            }],
+            DistrTypes::HalfNormal => vec![MixedParam::Float {
+                param: Param {
+                    default: 1_f64,
+                    range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                    name: "Scale".to_owned(),
+                    desc: Some(">0".to_owned()),
+                    speed: 0.1,
+                },
+            }],
+            DistrTypes::InverseGamma => vec![
                 MixedParam::Float {
                     param: Param {
                         default: 2_f64,
-                        range: RangeInclusive::new(BARELY_POSITIVE, 1000_f64),
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
                         name: "Shape".to_owned(),
                         desc: Some(">0".to_owned()),
                         speed: 0.1,
                     },
                 },
-            ],
-            DistrTypes::StudentsT => vec![
                 MixedParam::Float {
                     param: Param {
-                        default: 0_f64,
-                        range: RangeInclusive::new(f64::MIN, f64::MAX),
-                        name: "Location".to_owned(),
-                        desc: None,
-                        speed: 1.,
+                        default: 2_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Rate".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
                     },
                 },
+            ],
+            DistrTypes::InverseGaussian => vec![
                 MixedParam::Float {
                     param: Param {
                         default: 1_f64,
                         range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
-                        name: "Scale".to_owned(),
+                        name: "Mean".to_owned(),
                         desc: Some(">0".to_owned()),
                         speed: 0.1,
                     },
                 },
                 MixedParam::Float {
                     param: Param {
-                        default: 2_f64,
+                        default: 1_f64,
                         range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
-                        name: "Freedom".to_owned(),
+                        name: "Shape".to_owned(),
                         desc: Some(">0".to_owned()),
                         speed: 0.1,
                     },
                 },
             ],
-            DistrTypes::Triangular => vec![
+            DistrTypes::Kumaraswamy => vec![
                 MixedParam::Float {
                     param: Param {
-                        default: 0_f64,
-                        range: RangeInclusive::new(f64::MIN, f64::MAX),
-                        name: "Min".to_owned(),
-                        desc: Some("<Max".to_owned()),
+                        default: 2_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Shape A".to_owned(),
+                        desc: Some(">0".to_owned()),
                         speed: 0.1,
                     },
                 },
                 MixedParam::Float {
                     param: Param {
                         default: 5_f64,
-                        range: RangeInclusive::new(f64::MIN, f64::MAX),
-                        name: "Max".to_owned(),
-                        desc: Some(">Min".to_owned()),
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Shape B".to_owned(),
+                        desc: Some(">0".to_owned()),
                         speed: 0.1,
                     },
                 },
+            ],
+            DistrTypes::Laplace => vec![
                 MixedParam::Float {
                     param: Param {
-                        default: 2.5,
+                        default: 0_f64,
                         range: RangeInclusive::new(f64::MIN, f64::MAX),
-                        name: "Mode".to_owned(),
-                        desc: Some("Min <= Mode <= Max".to_owned()),
+                        name: "Location".to_owned(),
+                        desc: None,
+                        speed: 1.,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Scale".to_owned(),
+                        desc: Some(">0".to_owned()),
                         speed: 0.1,
                     },
                 },
             ],
-            DistrTypes::Uniform => vec![
+            DistrTypes::Levy => vec![
                 MixedParam::Float {
                     param: Param {
                         default: 0_f64,
                         range: RangeInclusive::new(f64::MIN, f64::MAX),
-                        name: "Min".to_owned(),
-                        desc: Some("<Max".to_owned()),
+                        name: "Location".to_owned(),
+                        desc: None,
                         speed: 0.1,
                     },
                 },
                 MixedParam::Float {
                     param: Param {
                         default: 1_f64,
-                        range: RangeInclusive::new(f64::MIN, f64::MAX),
-                        name: "Max".to_owned(),
-                        desc: Some(">Min".to_owned()),
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Scale".to_owned(),
+                        desc: Some(">0".to_owned()),
                         speed: 0.1,
                     },
                 },
             ],
-            DistrTypes::Weibull => vec![
+            DistrTypes::Logistic => vec![
                 MixedParam::Float {
                     param: Param {
-                        default: 5_f64,
+                        default: 0_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Location".to_owned(),
+                        desc: None,
+                        speed: 1.0,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
                         range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
-                        name: "Shape".to_owned(),
+                        name: "Scale".to_owned(),
                         desc: Some(">0".to_owned()),
                         speed: 0.1,
                     },
                 },
+            ],
+            DistrTypes::LogLogistic => vec![
                 MixedParam::Float {
                     param: Param {
                         default: 1_f64,
                         range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
                         name: "Scale".to_owned(),
+                        desc: Some(">0, the median".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 4_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Shape".to_owned(),
                         desc: Some(">0".to_owned()),
                         speed: 0.1,
                     },
                 },
             ],
-        }
-    }
-}
-pub trait Cont: Continuous<f64, f64> + ContinuousCDF<f64, f64> + Distribution<f64> {}
-impl Cont for Normal {}
-impl Cont for Gamma {}
-impl Cont for Beta {}
-impl Cont for Cauchy {}
-impl Cont for ChiSquared {}
-impl Cont for Exp {}
-impl Cont for FisherSnedecor {}
-impl Cont for Gumbel {}
-impl Cont for InverseGamma {}
-impl Cont for Laplace {}
-impl Cont for LogNormal {}
-impl Cont for Pareto {}
-impl Cont for StudentsT {}
-impl Cont for Triangular {}
-impl Cont for Uniform {}
-impl Cont for Weibull {}
+            DistrTypes::LogNormal => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Location".to_owned(),
+                        desc: None,
+                        speed: 1.0,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 0.5,
+                        range: RangeInclusive::new(BARELY_POSITIVE, 3.),
+                        name: "Scale".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.01,
+                    },
+                },
+            ],
+            DistrTypes::NoncentralChiSquared => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 3_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Freedom".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 2_f64,
+                        range: RangeInclusive::new(0., f64::MAX),
+                        name: "Noncentrality".to_owned(),
+                        desc: Some(">=0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::NoncentralFisherSnedecor => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 3_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Freedom 1".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 10_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Freedom 2".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 2_f64,
+                        range: RangeInclusive::new(0., f64::MAX),
+                        name: "Noncentrality".to_owned(),
+                        desc: Some(">=0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::NoncentralStudentsT => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 10_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Freedom".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Noncentrality".to_owned(),
+                        desc: None,
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::Pareto => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Scale".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 2_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, 1000_f64),
+                        name: "Shape".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::Pert => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Min".to_owned(),
+                        desc: Some("<Max".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 3_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Mode".to_owned(),
+                        desc: Some("Min <= Mode <= Max".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 10_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Max".to_owned(),
+                        desc: Some(">Min".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 4_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Lambda".to_owned(),
+                        desc: Some(">0, weight of the mode, 4 for classic PERT".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::Rayleigh => vec![MixedParam::Float {
+                param: Param {
+                    default: 1_f64,
+                    range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                    name: "Scale".to_owned(),
+                    desc: Some(">0".to_owned()),
+                    speed: 0.1,
+                },
+            }],
+            DistrTypes::ReversedWeibull => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 2_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Shape".to_owned(),
+                        desc: Some(">0, GEV with ξ = -1/Shape".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Scale".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Max".to_owned(),
+                        desc: Some("Upper bound of the support".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::Rice => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 2_f64,
+                        range: RangeInclusive::new(0_f64, f64::MAX),
+                        name: "Nu".to_owned(),
+                        desc: Some(">=0, distance of the signal from the origin".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Sigma".to_owned(),
+                        desc: Some(">0, noise scale".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::ScaledBeta => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 2_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Shape A".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 2_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Shape B".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Min".to_owned(),
+                        desc: Some("<Max".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 10_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Max".to_owned(),
+                        desc: Some(">Min".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::SkewNormal => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Location".to_owned(),
+                        desc: None,
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Scale".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 4_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Shape".to_owned(),
+                        desc: Some("Alpha, >0 skews right".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::SkewT => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Location".to_owned(),
+                        desc: None,
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Scale".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 4_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Shape A".to_owned(),
+                        desc: Some(">0, A > B skews right".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 2_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Shape B".to_owned(),
+                        desc: Some(">0, A = B is StudentsT".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::Stable => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 1.5_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, 2.),
+                        name: "Alpha".to_owned(),
+                        desc: Some("0 < Alpha <= 2, 2 is Normal".to_owned()),
+                        speed: 0.01,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(-1., 1.),
+                        name: "Beta".to_owned(),
+                        desc: Some("-1 <= Beta <= 1".to_owned()),
+                        speed: 0.01,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Scale".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Location".to_owned(),
+                        desc: None,
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::StudentsT => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Location".to_owned(),
+                        desc: None,
+                        speed: 1.,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Scale".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 2_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Freedom".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::Triangular => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Min".to_owned(),
+                        desc: Some("<Max".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 5_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Max".to_owned(),
+                        desc: Some(">Min".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 2.5,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Mode".to_owned(),
+                        desc: Some("Min <= Mode <= Max".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::Uniform => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Min".to_owned(),
+                        desc: Some("<Max".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(f64::MIN, f64::MAX),
+                        name: "Max".to_owned(),
+                        desc: Some(">Min".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::VonMises => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(-PI, PI),
+                        name: "Mean direction".to_owned(),
+                        desc: Some("-π <= μ <= π".to_owned()),
+                        speed: 0.05,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 2_f64,
+                        range: RangeInclusive::new(0_f64, 500.),
+                        name: "Concentration".to_owned(),
+                        desc: Some(">=0, 0 is uniform on the circle".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::Weibull => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 5_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Shape".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Scale".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::WrappedCauchy => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(-PI, PI),
+                        name: "Mean direction".to_owned(),
+                        desc: Some("-π <= μ <= π".to_owned()),
+                        speed: 0.05,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 0.5,
+                        range: RangeInclusive::new(0_f64, 0.999),
+                        name: "Concentration".to_owned(),
+                        desc: Some("0 <= ρ < 1, the mean resultant length".to_owned()),
+                        speed: 0.01,
+                    },
+                },
+            ],
+            DistrTypes::WrappedNormal => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 0_f64,
+                        range: RangeInclusive::new(-PI, PI),
+                        name: "Mean direction".to_owned(),
+                        desc: Some("-π <= μ <= π".to_owned()),
+                        speed: 0.05,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 1_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, 20.),
+                        name: "Std. dev.".to_owned(),
+                        desc: Some(">0, of the unwrapped normal".to_owned()),
+                        speed: 0.05,
+                    },
+                },
+            ],
+        }
+    }
+}
+pub trait Cont: Continuous<f64, f64> + ContinuousCDF<f64, f64> + Distribution<f64> {
+    /// Distributions on the circle return themselves here, so their summary
+    /// uses circular instead of linear moments.
+    fn as_circular(&self) -> Option<&dyn Circular> {
+        None
+    }
+}
+
+/// Distribution of an angle on [-π, π).
+pub trait Circular: Distribution<f64> {
+    fn mean_direction(&self) -> f64;
+    fn mean_resultant_length(&self) -> f64;
+}
+
+/// Integral of `f` over the support of `d`, split at its quantiles and at
+/// zero, where moments of reciprocals are singular.
+pub(crate) fn integrate_over(d: &dyn Cont, f: impl Fn(f64) -> f64) -> f64 {
+    let mut points: Vec<f64> = QUANTILE_LEVELS.iter().map(|&q| d.inverse_cdf(q)).collect();
+    points.extend([d.min(), d.max()]);
+    if d.min() < 0.0 && d.max() > 0.0 {
+        points.push(0.0);
+    }
+    integrate_pieces(f, points)
+}
+
+/// Whether the `k`-th moment of `d` exists for `k` up to three, as far as
+/// `d` reports its mean, variance and skewness.
+pub(crate) fn has_moment(d: &dyn Cont, k: i32) -> bool {
+    match k {
+        1 => d.mean().is_some(),
+        2 => d.variance().is_some(),
+        _ => d.skewness().is_some(),
+    }
+}
+
+/// `E[X^k]` of `d`, by numerical integration.
+pub(crate) fn raw_moment(d: &dyn Cont, k: i32) -> f64 {
+    integrate_over(d, |x| x.powi(k) * d.pdf(x))
+}
+
+/// Differential entropy of `d`, by numerical integration.
+pub(crate) fn numeric_entropy(d: &dyn Cont) -> f64 {
+    integrate_over(d, |x| {
+        let p = d.pdf(x);
+        if p > 0.0 { -p * p.ln() } else { 0.0 }
+    })
+}
+impl Cont for Normal {}
+impl Cont for Gamma {}
+impl Cont for Beta {}
+impl Cont for Burr {}
+impl Cont for Cauchy {}
+impl Cont for Chi {}
+impl Cont for ChiSquared {}
+impl Cont for Compound {}
+impl Cont for Convolution {}
+impl Cont for Dagum {}
+impl Cont for Erlang {}
+impl Cont for Exp {}
+impl Cont for Extremum {}
+impl Cont for FisherSnedecor {}
+impl Cont for Gev {}
+impl Cont for FoldedNormal {}
+impl Cont for GeneralizedPareto {}
+impl Cont for Gumbel {}
+impl Cont for HalfCauchy {}
+impl Cont for InverseGamma {}
+impl Cont for InverseGaussian {}
+impl Cont for Kumaraswamy {}
+impl Cont for Laplace {}
+impl Cont for Levy {}
+impl Cont for Logistic {}
+impl Cont for LogLogistic {}
+impl Cont for LogNormal {}
+impl Cont for Mixture {
+    fn as_circular(&self) -> Option<&dyn Circular> {
+        if self.is_circular() { Some(self) } else { None }
+    }
+}
+impl Cont for NoncentralChiSquared {}
+impl Cont for NoncentralFisherSnedecor {}
+impl Cont for NoncentralStudentsT {}
+impl Cont for OrderStatistic {}
+impl Cont for Pareto {}
+impl Cont for Product {}
+impl Cont for Rayleigh {}
+impl Cont for Rice {}
+impl Cont for ScaledBeta {}
+impl Cont for SkewNormal {}
+impl Cont for SkewT {}
+impl Cont for Stable {}
+impl Cont for StudentsT {}
+impl Cont for Transformed {}
+impl Cont for Triangular {}
+impl<D: Cont + ?Sized> Cont for Truncated<D> {}
+impl Cont for Uniform {}
+impl Cont for Weibull {}
+impl Cont for VonMises {
+    fn as_circular(&self) -> Option<&dyn Circular> {
+        Some(self)
+    }
+}
+impl Cont for WrappedCauchy {
+    fn as_circular(&self) -> Option<&dyn Circular> {
+        Some(self)
+    }
+}
+impl Cont for WrappedNormal {
+    fn as_circular(&self) -> Option<&dyn Circular> {
+        Some(self)
+    }
+}
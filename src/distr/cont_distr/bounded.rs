@@ -0,0 +1,231 @@
+use crate::distr::numeric::{EULER_MASCHERONI, moments_from_raw};
+use statrs::{
+    distribution::{Beta, Continuous, ContinuousCDF},
+    function::{beta::beta, gamma::digamma},
+    statistics::{Distribution, Max, Min},
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum BoundedError {
+    BoundsInvalid,
+    ModeOutOfBounds,
+    ShapeNotPositive,
+}
+
+impl std::fmt::Display for BoundedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BoundedError::BoundsInvalid => write!(f, "Min is not finite and less than Max"),
+            BoundedError::ModeOutOfBounds => write!(f, "Mode is not within [Min, Max]"),
+            BoundedError::ShapeNotPositive => {
+                write!(f, "Shape is NaN, infinite, zero or less than zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BoundedError {}
+
+fn check_shape(shape: f64) -> Result<(), BoundedError> {
+    if !shape.is_finite() || shape <= 0.0 {
+        return Err(BoundedError::ShapeNotPositive);
+    }
+    Ok(())
+}
+
+/// Beta distribution with shapes `a` and `b` stretched from [0, 1] to
+/// [`min`, `max`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ScaledBeta {
+    beta: Beta,
+    min: f64,
+    width: f64,
+}
+
+impl ScaledBeta {
+    pub fn new(shape_a: f64, shape_b: f64, min: f64, max: f64) -> Result<ScaledBeta, BoundedError> {
+        check_shape(shape_a)?;
+        check_shape(shape_b)?;
+        if !min.is_finite() || !max.is_finite() || min >= max {
+            return Err(BoundedError::BoundsInvalid);
+        }
+        Ok(ScaledBeta {
+            beta: Beta::new(shape_a, shape_b).map_err(|_| BoundedError::ShapeNotPositive)?,
+            min,
+            width: max - min,
+        })
+    }
+
+    /// PERT distribution of an estimate between `min` and `max` with most
+    /// likely value `mode`. `lambda` weights the mode, classic PERT uses 4.
+    pub fn pert(min: f64, max: f64, mode: f64, lambda: f64) -> Result<ScaledBeta, BoundedError> {
+        if !min.is_finite() || !max.is_finite() || min >= max {
+            return Err(BoundedError::BoundsInvalid);
+        }
+        if !(min..=max).contains(&mode) {
+            return Err(BoundedError::ModeOutOfBounds);
+        }
+        check_shape(lambda)?;
+        let width = max - min;
+        ScaledBeta::new(
+            1.0 + lambda * (mode - min) / width,
+            1.0 + lambda * (max - mode) / width,
+            min,
+            max,
+        )
+    }
+
+    fn standardize(&self, x: f64) -> f64 {
+        (x - self.min) / self.width
+    }
+}
+
+impl ContinuousCDF<f64, f64> for ScaledBeta {
+    fn cdf(&self, x: f64) -> f64 {
+        self.beta.cdf(self.standardize(x).clamp(0.0, 1.0))
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        self.beta.sf(self.standardize(x).clamp(0.0, 1.0))
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        self.min + self.width * self.beta.inverse_cdf(p)
+    }
+}
+
+impl Min<f64> for ScaledBeta {
+    fn min(&self) -> f64 {
+        self.min
+    }
+}
+
+impl Max<f64> for ScaledBeta {
+    fn max(&self) -> f64 {
+        self.min + self.width
+    }
+}
+
+impl Distribution<f64> for ScaledBeta {
+    fn mean(&self) -> Option<f64> {
+        Some(self.min + self.width * self.beta.mean()?)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        Some(self.width * self.width * self.beta.variance()?)
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        Some(self.beta.entropy()? + self.width.ln())
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        self.beta.skewness()
+    }
+}
+
+impl Continuous<f64, f64> for ScaledBeta {
+    fn pdf(&self, x: f64) -> f64 {
+        let z = self.standardize(x);
+        if !(0.0..=1.0).contains(&z) {
+            return 0.0;
+        }
+        self.beta.pdf(z) / self.width
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+/// Kumaraswamy distribution on [0, 1] with shapes `a` and `b`,
+/// `F(x) = 1 - (1 - x^a)^b`. Similar to the Beta but with a closed form CDF.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Kumaraswamy {
+    a: f64,
+    b: f64,
+}
+
+impl Kumaraswamy {
+    pub fn new(a: f64, b: f64) -> Result<Kumaraswamy, BoundedError> {
+        check_shape(a)?;
+        check_shape(b)?;
+        Ok(Kumaraswamy { a, b })
+    }
+
+    /// `E[X^n] = b B(1 + n/a, b)`
+    fn raw_moment(&self, n: f64) -> f64 {
+        self.b * beta(1.0 + n / self.a, self.b)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Kumaraswamy {
+    fn cdf(&self, x: f64) -> f64 {
+        1.0 - self.sf(x)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 1.0;
+        }
+        if x >= 1.0 {
+            return 0.0;
+        }
+        (1.0 - x.powf(self.a)).powf(self.b)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return 0.0;
+        }
+        if p >= 1.0 {
+            return 1.0;
+        }
+        (1.0 - (1.0 - p).powf(1.0 / self.b)).powf(1.0 / self.a)
+    }
+}
+
+impl Min<f64> for Kumaraswamy {
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for Kumaraswamy {
+    fn max(&self) -> f64 {
+        1.0
+    }
+}
+
+impl Distribution<f64> for Kumaraswamy {
+    fn mean(&self) -> Option<f64> {
+        Some(self.raw_moment(1.0))
+    }
+
+    fn variance(&self) -> Option<f64> {
+        moments_from_raw([1.0, 2.0, 3.0].map(|r| Some(self.raw_moment(r)))).1
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        // the harmonic number H_b = ψ(b + 1) + γ
+        let harmonic = digamma(self.b + 1.0) + EULER_MASCHERONI;
+        Some((1.0 - 1.0 / self.b) + (1.0 - 1.0 / self.a) * harmonic - (self.a * self.b).ln())
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        moments_from_raw([1.0, 2.0, 3.0].map(|r| Some(self.raw_moment(r)))).2
+    }
+}
+
+impl Continuous<f64, f64> for Kumaraswamy {
+    fn pdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            return 0.0;
+        }
+        self.a * self.b * x.powf(self.a - 1.0) * (1.0 - x.powf(self.a)).powf(self.b - 1.0)
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
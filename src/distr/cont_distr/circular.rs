@@ -0,0 +1,342 @@
+use super::Circular;
+use crate::distr::numeric::{bessel_i0e, bessel_i1e, invert_cdf};
+use statrs::{
+    distribution::{Continuous, ContinuousCDF},
+    function::erf::erfc,
+    statistics::{Distribution, Max, Min},
+};
+use std::f64::consts::{FRAC_1_SQRT_2, PI, TAU};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum CircularError {
+    MeanNotFinite,
+    ConcentrationInvalid,
+    StdDevNotPositive,
+}
+
+impl std::fmt::Display for CircularError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircularError::MeanNotFinite => write!(f, "Mean direction is NaN or infinite"),
+            CircularError::ConcentrationInvalid => {
+                write!(f, "Concentration is NaN or outside of its range")
+            }
+            CircularError::StdDevNotPositive => {
+                write!(f, "Std. dev. is NaN, infinite, zero or less than zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CircularError {}
+
+/// Maps an angle onto [-π, π).
+fn wrap(x: f64) -> f64 {
+    (x + PI).rem_euclid(TAU) - PI
+}
+
+fn check_mean(mean: f64) -> Result<f64, CircularError> {
+    if !mean.is_finite() {
+        return Err(CircularError::MeanNotFinite);
+    }
+    Ok(wrap(mean))
+}
+
+// The support is fixed to [-π, π) for all circular distributions.
+macro_rules! impl_circular_support {
+    ($t:ty) => {
+        impl Min<f64> for $t {
+            fn min(&self) -> f64 {
+                -PI
+            }
+        }
+
+        impl Max<f64> for $t {
+            fn max(&self) -> f64 {
+                PI
+            }
+        }
+    };
+}
+
+/// Von Mises distribution with mean direction `μ` and concentration `κ`,
+/// the circular analogue of the normal distribution.
+#[derive(Clone, PartialEq, Debug)]
+pub struct VonMises {
+    mean: f64,
+    kappa: f64,
+    // I_p(κ) / (p I_0(κ)) for p >= 1, the Fourier coefficients of the CDF
+    cdf_coeffs: Vec<f64>,
+}
+
+impl VonMises {
+    pub fn new(mean: f64, kappa: f64) -> Result<VonMises, CircularError> {
+        let mean = check_mean(mean)?;
+        if !kappa.is_finite() || kappa < 0.0 {
+            return Err(CircularError::ConcentrationInvalid);
+        }
+        Ok(VonMises {
+            mean,
+            kappa,
+            cdf_coeffs: Self::cdf_coeffs(kappa),
+        })
+    }
+
+    fn cdf_coeffs(kappa: f64) -> Vec<f64> {
+        if kappa == 0.0 {
+            return Vec::new();
+        }
+        // I_p / I_0 falls off like exp(-p² / 2κ), so this leaves out terms
+        // below exp(-40). The ratios r_p = I_p / I_(p-1) come from the
+        // backward recurrence r_p = κ / (2p + κ r_(p+1)), started well past
+        // that point so the error of the initial r = 0 has died out.
+        let terms = (80.0 * kappa).sqrt().ceil() as usize + 20;
+        let mut ratios = vec![0.0; terms + 1];
+        let mut r = 0.0;
+        for p in (1..=terms + 60).rev() {
+            r = kappa / (2.0 * p as f64 + kappa * r);
+            if p <= terms {
+                ratios[p] = r;
+            }
+        }
+        let mut a = 1.0;
+        (1..=terms)
+            .map(|p| {
+                a *= ratios[p];
+                a / p as f64
+            })
+            .collect()
+    }
+
+    fn mean_resultant(&self) -> f64 {
+        bessel_i1e(self.kappa) / bessel_i0e(self.kappa)
+    }
+}
+
+impl_circular_support!(VonMises);
+
+impl ContinuousCDF<f64, f64> for VonMises {
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= -PI {
+            return 0.0;
+        }
+        if x >= PI {
+            return 1.0;
+        }
+        // antiderivative of the Fourier series of the density around μ
+        let antiderivative = |t: f64| {
+            t / TAU
+                + self
+                    .cdf_coeffs
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| c * ((i + 1) as f64 * t).sin())
+                    .sum::<f64>()
+                    / PI
+        };
+        (antiderivative(x - self.mean) - antiderivative(-PI - self.mean)).clamp(0.0, 1.0)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        invert_cdf(|x| self.cdf(x), p.clamp(0.0, 1.0), -PI, PI)
+    }
+}
+
+impl Distribution<f64> for VonMises {
+    fn entropy(&self) -> Option<f64> {
+        let k = self.kappa;
+        Some(-k * self.mean_resultant() + (TAU * bessel_i0e(k)).ln() + k)
+    }
+}
+
+impl Continuous<f64, f64> for VonMises {
+    fn pdf(&self, x: f64) -> f64 {
+        if !(-PI..=PI).contains(&x) {
+            return 0.0;
+        }
+        (self.kappa * ((x - self.mean).cos() - 1.0)).exp() / (TAU * bessel_i0e(self.kappa))
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+impl Circular for VonMises {
+    fn mean_direction(&self) -> f64 {
+        self.mean
+    }
+
+    fn mean_resultant_length(&self) -> f64 {
+        self.mean_resultant()
+    }
+}
+
+/// Normal distribution with mean `μ` and standard deviation `σ` wrapped
+/// around the unit circle.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct WrappedNormal {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl WrappedNormal {
+    pub fn new(mean: f64, std_dev: f64) -> Result<WrappedNormal, CircularError> {
+        let mean = check_mean(mean)?;
+        if !std_dev.is_finite() || std_dev <= 0.0 {
+            return Err(CircularError::StdDevNotPositive);
+        }
+        Ok(WrappedNormal { mean, std_dev })
+    }
+
+    // Windings needed so that the dropped normal mass is negligible for
+    // offsets within [-2π, 2π].
+    fn windings(&self) -> i32 {
+        ((10.0 * self.std_dev) / TAU).ceil() as i32 + 1
+    }
+}
+
+impl_circular_support!(WrappedNormal);
+
+impl ContinuousCDF<f64, f64> for WrappedNormal {
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= -PI {
+            return 0.0;
+        }
+        if x >= PI {
+            return 1.0;
+        }
+        let phi = |z: f64| 0.5 * erfc(-z * FRAC_1_SQRT_2);
+        let n = self.windings();
+        let (upper, lower) = (x - self.mean, -PI - self.mean);
+        (-n..=n)
+            .map(|k| {
+                let shift = TAU * k as f64;
+                phi((upper + shift) / self.std_dev) - phi((lower + shift) / self.std_dev)
+            })
+            .sum::<f64>()
+            .clamp(0.0, 1.0)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        invert_cdf(|x| self.cdf(x), p.clamp(0.0, 1.0), -PI, PI)
+    }
+}
+
+impl Distribution<f64> for WrappedNormal {
+    fn entropy(&self) -> Option<f64> {
+        // the trapezoidal rule converges very quickly for periodic integrands
+        let n = 1024;
+        let h = TAU / n as f64;
+        let entropy = (0..n)
+            .map(|i| self.pdf(-PI + i as f64 * h))
+            .filter(|&f| f > 0.0)
+            .map(|f| -f * f.ln() * h)
+            .sum();
+        Some(entropy)
+    }
+}
+
+impl Continuous<f64, f64> for WrappedNormal {
+    fn pdf(&self, x: f64) -> f64 {
+        if !(-PI..=PI).contains(&x) {
+            return 0.0;
+        }
+        let n = self.windings();
+        let norm = 1.0 / (self.std_dev * TAU.sqrt());
+        (-n..=n)
+            .map(|k| {
+                let z = (x - self.mean + TAU * k as f64) / self.std_dev;
+                norm * (-0.5 * z * z).exp()
+            })
+            .sum()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+impl Circular for WrappedNormal {
+    fn mean_direction(&self) -> f64 {
+        self.mean
+    }
+
+    fn mean_resultant_length(&self) -> f64 {
+        (-self.std_dev * self.std_dev / 2.0).exp()
+    }
+}
+
+/// Cauchy distribution wrapped around the unit circle, parametrized by its
+/// mean direction `μ` and mean resultant length `0 <= ρ < 1`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct WrappedCauchy {
+    mean: f64,
+    rho: f64,
+}
+
+impl WrappedCauchy {
+    pub fn new(mean: f64, rho: f64) -> Result<WrappedCauchy, CircularError> {
+        let mean = check_mean(mean)?;
+        if !(0.0..1.0).contains(&rho) {
+            return Err(CircularError::ConcentrationInvalid);
+        }
+        Ok(WrappedCauchy { mean, rho })
+    }
+
+    // Continuous antiderivative of the density centered at 0, defined on the
+    // whole real line so that windings are counted.
+    fn antiderivative(&self, t: f64) -> f64 {
+        let turns = ((t + PI) / TAU).floor();
+        let c = (1.0 + self.rho) / (1.0 - self.rho);
+        turns + 0.5 + (c * (wrap(t) / 2.0).tan()).atan() / PI
+    }
+}
+
+impl_circular_support!(WrappedCauchy);
+
+impl ContinuousCDF<f64, f64> for WrappedCauchy {
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= -PI {
+            return 0.0;
+        }
+        if x >= PI {
+            return 1.0;
+        }
+        (self.antiderivative(x - self.mean) - self.antiderivative(-PI - self.mean)).clamp(0.0, 1.0)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        invert_cdf(|x| self.cdf(x), p.clamp(0.0, 1.0), -PI, PI)
+    }
+}
+
+impl Distribution<f64> for WrappedCauchy {
+    fn entropy(&self) -> Option<f64> {
+        Some((TAU * (1.0 - self.rho * self.rho)).ln())
+    }
+}
+
+impl Continuous<f64, f64> for WrappedCauchy {
+    fn pdf(&self, x: f64) -> f64 {
+        if !(-PI..=PI).contains(&x) {
+            return 0.0;
+        }
+        let r2 = self.rho * self.rho;
+        (1.0 - r2) / (TAU * (1.0 + r2 - 2.0 * self.rho * (x - self.mean).cos()))
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+impl Circular for WrappedCauchy {
+    fn mean_direction(&self) -> f64 {
+        self.mean
+    }
+
+    fn mean_resultant_length(&self) -> f64 {
+        self.rho
+    }
+}
@@ -0,0 +1,163 @@
+use super::{Cont, Table};
+use crate::distr::numeric::{
+    QUANTILE_LEVELS, converged_integral, integrate_pieces, moments_from_raw, raw_moments,
+};
+use statrs::{
+    distribution::{Continuous, ContinuousCDF},
+    statistics::{Distribution, Max, Min},
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum CompoundError {
+    ParameterInvalid,
+}
+
+impl std::fmt::Display for CompoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompoundError::ParameterInvalid => {
+                write!(
+                    f,
+                    "The mixing distribution takes values the parameter cannot"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for CompoundError {}
+
+/// Values of the mixed parameter to split integrals over it at, the
+/// outermost ones cut off its tails.
+pub(crate) fn mixing_points(mixing: &dyn Cont) -> Vec<f64> {
+    QUANTILE_LEVELS
+        .iter()
+        .map(|&q| mixing.inverse_cdf(q))
+        .collect()
+}
+
+/// Mean, variance and skewness of a compound distribution, from the raw
+/// moments `raw(θ, r)` of the conditional distributions integrated over the
+/// mixing distribution.
+///
+/// A moment is left out where the conditional distributions lack it, or
+/// where it does not converge within the outermost `1e-9` of the mixing
+/// distribution on either side.
+pub(crate) fn compound_moments(
+    mixing: &dyn Cont,
+    raw: impl Fn(f64, usize) -> Option<f64>,
+) -> (Option<f64>, Option<f64>, Option<f64>) {
+    let points = mixing_points(mixing);
+    let last = points.len() - 1;
+    let tails = [(points[0], points[1]), (points[last - 1], points[last])];
+    let moment = |r: usize| {
+        if points.iter().any(|&t| raw(t, r).is_none()) {
+            return None;
+        }
+        let integrand = |t: f64| raw(t, r).unwrap_or(0.0) * mixing.pdf(t);
+        converged_integral(integrand, points.clone(), &tails)
+    };
+    moments_from_raw([moment(1), moment(2), moment(3)])
+}
+
+/// Marginal distribution of `X` when a parameter `θ` of its distribution is
+/// itself drawn from a mixing distribution, `f(x) = ∫ f(x | θ) π(θ) dθ`.
+///
+/// `family` gives the conditional distribution of `X` at every value of `θ`
+/// and `None` where the parameter is invalid. Density and CDF are integrated
+/// over `θ` and tabulated, tails beyond a thousand interquartile ranges are
+/// left out. The moments follow from those of the conditional distributions.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Compound {
+    table: Table,
+    moments: (Option<f64>, Option<f64>, Option<f64>),
+}
+
+impl Compound {
+    pub fn new(
+        family: impl Fn(f64) -> Option<Box<dyn Cont>>,
+        mixing: &dyn Cont,
+    ) -> Result<Compound, CompoundError> {
+        let points = mixing_points(mixing);
+        let conditionals = points
+            .iter()
+            .map(|&t| family(t))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(CompoundError::ParameterInvalid)?;
+        let min = conditionals
+            .iter()
+            .map(|d| d.min())
+            .fold(f64::INFINITY, f64::min);
+        let max = conditionals
+            .iter()
+            .map(|d| d.max())
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        // relative to the mass of the mixing distribution between the
+        // outermost points, so the cut off tails do not leave a gap
+        let mass = integrate_pieces(|t| mixing.pdf(t), points.clone());
+        let marginal = |f: &dyn Fn(&dyn Cont) -> f64| {
+            let integrand = |t: f64| family(t).map_or(0.0, |d| f(d.as_ref()) * mixing.pdf(t));
+            integrate_pieces(integrand, points.clone()) / mass
+        };
+        let table = Table::new(
+            |x| marginal(&|d| d.pdf(x)),
+            |x| marginal(&|d| d.cdf(x)).clamp(0.0, 1.0),
+            (min, max),
+        );
+        let moments = compound_moments(mixing, |t, r| {
+            family(t).and_then(|d| raw_moments(d.as_ref())[r - 1])
+        });
+        Ok(Compound { table, moments })
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Compound {
+    fn cdf(&self, x: f64) -> f64 {
+        self.table.cdf(x)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        self.table.inverse_cdf(p)
+    }
+}
+
+impl Min<f64> for Compound {
+    fn min(&self) -> f64 {
+        self.table.min()
+    }
+}
+
+impl Max<f64> for Compound {
+    fn max(&self) -> f64 {
+        self.table.max()
+    }
+}
+
+impl Distribution<f64> for Compound {
+    fn mean(&self) -> Option<f64> {
+        self.moments.0
+    }
+
+    fn variance(&self) -> Option<f64> {
+        self.moments.1
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        Some(self.table.entropy())
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        self.moments.2
+    }
+}
+
+impl Continuous<f64, f64> for Compound {
+    fn pdf(&self, x: f64) -> f64 {
+        self.table.pdf(x)
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
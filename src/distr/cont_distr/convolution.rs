@@ -0,0 +1,291 @@
+use super::Cont;
+use crate::distr::numeric::{central_range, fft, sum_moments};
+use statrs::{
+    distribution::{Continuous, ContinuousCDF},
+    statistics::{Distribution, Max, Min},
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum ConvolutionError {
+    CountZero,
+    NoTerms,
+}
+
+impl std::fmt::Display for ConvolutionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConvolutionError::CountZero => write!(f, "Every term must be added at least once"),
+            ConvolutionError::NoTerms => write!(f, "The sum has no terms"),
+        }
+    }
+}
+
+impl std::error::Error for ConvolutionError {}
+
+/// Number of cells the range of the sum is split into.
+const CELLS: f64 = 65536.0;
+
+/// Distribution of a sum of independent variables, each distribution paired
+/// with the number of times it is added.
+///
+/// Every term is discretized into the probabilities of equal-width cells,
+/// which are convolved by FFT. The density interpolates linearly between
+/// the cell centers of the result, the CDF between the cell edges. Tails
+/// beyond a thousand interquartile ranges are cut off and the remaining mass
+/// renormalized. Mean, variance and skewness are exact.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Convolution {
+    // center of the first cell
+    origin: f64,
+    step: f64,
+    mass: Vec<f64>,
+    cumulative: Vec<f64>,
+    min: f64,
+    max: f64,
+    moments: (Option<f64>, Option<f64>, Option<f64>),
+}
+
+impl Convolution {
+    pub fn new(terms: &[(&dyn Cont, u64)]) -> Result<Convolution, ConvolutionError> {
+        if terms.is_empty() {
+            return Err(ConvolutionError::NoTerms);
+        }
+        if terms.iter().any(|&(_, n)| n == 0) {
+            return Err(ConvolutionError::CountZero);
+        }
+        let ranges: Vec<(f64, f64)> = terms
+            .iter()
+            .map(|&(d, _)| central_range(|p| d.inverse_cdf(p)))
+            .collect();
+        let width: f64 = terms
+            .iter()
+            .zip(&ranges)
+            .map(|(&(_, n), (lo, hi))| n as f64 * (hi - lo))
+            .sum();
+        let step = width / CELLS;
+        let cells: Vec<Vec<f64>> = terms
+            .iter()
+            .zip(&ranges)
+            .map(|(&(d, _), &(lo, hi))| cell_masses(d, lo, hi, step))
+            .collect();
+
+        // long enough for the transforms not to wrap around
+        let len = 1 + terms
+            .iter()
+            .zip(&cells)
+            .map(|(&(_, n), c)| n as usize * (c.len() - 1))
+            .sum::<usize>();
+        let size = len.next_power_of_two();
+        let (mut re, mut im) = (vec![1.0; size], vec![0.0; size]);
+        for (&(_, n), c) in terms.iter().zip(&cells) {
+            let (mut a, mut b) = (c.clone(), vec![0.0; size]);
+            a.resize(size, 0.0);
+            fft(&mut a, &mut b, false);
+            for k in 0..size {
+                // n-th power in polar form
+                let r = a[k].hypot(b[k]).powf(n as f64);
+                let theta = b[k].atan2(a[k]) * n as f64;
+                let (x, y) = (r * theta.cos(), r * theta.sin());
+                (re[k], im[k]) = (re[k] * x - im[k] * y, re[k] * y + im[k] * x);
+            }
+        }
+        fft(&mut re, &mut im, true);
+        re.truncate(len);
+
+        // rounding leaves tiny negative values in the tails
+        let mut mass: Vec<f64> = re.into_iter().map(|p| p.max(0.0)).collect();
+        let total: f64 = mass.iter().sum();
+        mass.iter_mut().for_each(|p| *p /= total);
+        let cumulative = mass
+            .iter()
+            .scan(0.0, |acc, p| {
+                *acc += p;
+                Some(*acc)
+            })
+            .collect();
+
+        let parts: Vec<(u64, &dyn Distribution<f64>)> = terms
+            .iter()
+            .map(|&(d, n)| (n, d as &dyn Distribution<f64>))
+            .collect();
+        Ok(Convolution {
+            origin: terms
+                .iter()
+                .zip(&ranges)
+                .map(|(&(_, n), (lo, _))| n as f64 * (lo + step / 2.0))
+                .sum(),
+            step,
+            mass,
+            cumulative,
+            min: terms.iter().map(|&(d, n)| n as f64 * d.min()).sum(),
+            max: terms.iter().map(|&(d, n)| n as f64 * d.max()).sum(),
+            moments: sum_moments(&parts),
+        })
+    }
+
+    /// Probability of the `i`-th cell, zero beyond the ends.
+    fn cell(&self, i: isize) -> f64 {
+        usize::try_from(i)
+            .ok()
+            .and_then(|i| self.mass.get(i))
+            .copied()
+            .unwrap_or(0.0)
+    }
+}
+
+/// Most cell edges of a term its distribution is evaluated at, the tails at
+/// the edges in between are interpolated.
+const KNOTS: usize = 4096;
+
+/// Probabilities of the cells of width `step` from `lo` onwards covering
+/// `hi`. Edges above the median use the survival function, so the upper
+/// tail keeps its precision.
+///
+/// The tails are only evaluated at up to `KNOTS` evenly spaced edges and
+/// interpolated by cubic Hermite polynomials in between, with the density
+/// as their slope. Between knots on either side of the median they are
+/// evaluated at every edge.
+fn cell_masses(d: &dyn Cont, lo: f64, hi: f64, step: f64) -> Vec<f64> {
+    let count = ((hi - lo) / step).ceil().max(1.0) as usize;
+    let median = d.inverse_cdf(0.5);
+    let edges: Vec<f64> = (0..=count).map(|i| lo + i as f64 * step).collect();
+    let tail = |x: f64| if x <= median { d.cdf(x) } else { d.sf(x) };
+    // every `stride`-th edge is a knot, and the last one
+    let stride = count.div_ceil(KNOTS);
+    // edge, tail and its slope
+    let knot = |x: f64| {
+        let slope = if x <= median { d.pdf(x) } else { -d.pdf(x) };
+        (x, tail(x), slope)
+    };
+    let mut tails = vec![0.0; count + 1];
+    let (mut i, mut start) = (0, knot(edges[0]));
+    tails[0] = start.1;
+    while i < count {
+        let j = (i + stride).min(count);
+        let end = knot(edges[j]);
+        tails[j] = end.1;
+        for k in i + 1..j {
+            tails[k] = if (start.0 <= median) != (end.0 <= median) {
+                tail(edges[k])
+            } else {
+                hermite(start, end, edges[k])
+            };
+        }
+        (i, start) = (j, end);
+    }
+    (0..count)
+        .map(|i| {
+            let p = if edges[i + 1] <= median {
+                tails[i + 1] - tails[i]
+            } else if edges[i] > median {
+                tails[i] - tails[i + 1]
+            } else {
+                d.cdf(edges[i + 1]) - tails[i]
+            };
+            p.max(0.0)
+        })
+        .collect()
+}
+
+/// Cubic through `(a, ya)` and `(b, yb)` with slopes `ma` and `mb` there,
+/// at `x`. A linear one if either slope is not finite.
+fn hermite((a, ya, ma): (f64, f64, f64), (b, yb, mb): (f64, f64, f64), x: f64) -> f64 {
+    let (h, t) = (b - a, (x - a) / (b - a));
+    if !ma.is_finite() || !mb.is_finite() {
+        return ya + t * (yb - ya);
+    }
+    let t2 = t * t;
+    let t3 = t2 * t;
+    (2.0 * t3 - 3.0 * t2 + 1.0) * ya
+        + (t3 - 2.0 * t2 + t) * h * ma
+        + (3.0 * t2 - 2.0 * t3) * yb
+        + (t3 - t2) * h * mb
+}
+
+impl ContinuousCDF<f64, f64> for Convolution {
+    fn cdf(&self, x: f64) -> f64 {
+        let u = (x - self.origin) / self.step + 0.5;
+        if u <= 0.0 {
+            return 0.0;
+        }
+        if u >= self.mass.len() as f64 {
+            return 1.0;
+        }
+        let i = u.floor() as usize;
+        let below = if i == 0 { 0.0 } else { self.cumulative[i - 1] };
+        (below + (u - i as f64) * self.mass[i]).min(1.0)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min;
+        }
+        if p >= 1.0 {
+            return self.max;
+        }
+        let i = self
+            .cumulative
+            .partition_point(|&c| c < p)
+            .min(self.mass.len() - 1);
+        let below = if i == 0 { 0.0 } else { self.cumulative[i - 1] };
+        let fraction = if self.mass[i] > 0.0 {
+            (p - below) / self.mass[i]
+        } else {
+            0.5
+        };
+        self.origin + (i as f64 - 0.5 + fraction) * self.step
+    }
+}
+
+impl Min<f64> for Convolution {
+    fn min(&self) -> f64 {
+        self.min
+    }
+}
+
+impl Max<f64> for Convolution {
+    fn max(&self) -> f64 {
+        self.max
+    }
+}
+
+impl Distribution<f64> for Convolution {
+    fn mean(&self) -> Option<f64> {
+        self.moments.0
+    }
+
+    fn variance(&self) -> Option<f64> {
+        self.moments.1
+    }
+
+    /// Entropy of the density on the cells.
+    fn entropy(&self) -> Option<f64> {
+        let entropy = self
+            .mass
+            .iter()
+            .filter(|&&p| p > 0.0)
+            .map(|&p| -p * (p / self.step).ln())
+            .sum();
+        Some(entropy)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        self.moments.2
+    }
+}
+
+impl Continuous<f64, f64> for Convolution {
+    fn pdf(&self, x: f64) -> f64 {
+        let u = (x - self.origin) / self.step;
+        if u <= -1.0 || u >= self.mass.len() as f64 {
+            return 0.0;
+        }
+        let i = u.floor();
+        let (left, right) = (self.cell(i as isize), self.cell(i as isize + 1));
+        (left + (u - i) * (right - left)) / self.step
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
@@ -0,0 +1,340 @@
+use crate::distr::numeric::EULER_MASCHERONI;
+use statrs::{
+    distribution::{Continuous, ContinuousCDF},
+    function::gamma::gamma,
+    statistics::{Distribution, Max, Min},
+};
+use std::f64::consts::PI;
+
+// Below this |shape| the shape-zero (Gumbel / exponential) formulas are used,
+// the general moment formulas suffer from cancellation close to zero.
+const SHAPE_EPS: f64 = 1e-6;
+const ZETA_3: f64 = 1.202_056_903_159_594_2;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum ExtremeValueError {
+    LocationNotFinite,
+    ScaleNotPositive,
+    ShapeNotFinite,
+    ShapeNotPositive,
+}
+
+impl std::fmt::Display for ExtremeValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExtremeValueError::LocationNotFinite => write!(f, "Location is NaN or infinite"),
+            ExtremeValueError::ScaleNotPositive => {
+                write!(f, "Scale is NaN, infinite, zero or less than zero")
+            }
+            ExtremeValueError::ShapeNotFinite => write!(f, "Shape is NaN or infinite"),
+            ExtremeValueError::ShapeNotPositive => {
+                write!(f, "Shape is NaN, infinite, zero or less than zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExtremeValueError {}
+
+fn check_params(location: f64, scale: f64, shape: f64) -> Result<(), ExtremeValueError> {
+    if !location.is_finite() {
+        return Err(ExtremeValueError::LocationNotFinite);
+    }
+    if !scale.is_finite() || scale <= 0.0 {
+        return Err(ExtremeValueError::ScaleNotPositive);
+    }
+    if !shape.is_finite() {
+        return Err(ExtremeValueError::ShapeNotFinite);
+    }
+    Ok(())
+}
+
+/// Generalized extreme value distribution with location `μ`, scale `σ` and
+/// shape `ξ`. `ξ > 0` is the Fréchet type, `ξ = 0` Gumbel and `ξ < 0` the
+/// reversed Weibull type.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Gev {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+impl Gev {
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<Gev, ExtremeValueError> {
+        check_params(location, scale, shape)?;
+        Ok(Gev {
+            location,
+            scale,
+            shape,
+        })
+    }
+
+    /// Fréchet with shape `α`, scale `s` and minimum `m`,
+    /// i.e. `F(x) = exp(-((x - m) / s)^-α)` for `x > m`.
+    pub fn frechet(shape: f64, scale: f64, min: f64) -> Result<Gev, ExtremeValueError> {
+        if !shape.is_finite() || shape <= 0.0 {
+            return Err(ExtremeValueError::ShapeNotPositive);
+        }
+        Gev::new(min + scale, scale / shape, 1.0 / shape)
+    }
+
+    /// Reversed Weibull with shape `α`, scale `s` and maximum `m`,
+    /// i.e. `F(x) = exp(-((m - x) / s)^α)` for `x < m`.
+    pub fn reversed_weibull(shape: f64, scale: f64, max: f64) -> Result<Gev, ExtremeValueError> {
+        if !shape.is_finite() || shape <= 0.0 {
+            return Err(ExtremeValueError::ShapeNotPositive);
+        }
+        Gev::new(max - scale, scale / shape, -1.0 / shape)
+    }
+
+    fn is_gumbel(&self) -> bool {
+        self.shape.abs() < SHAPE_EPS
+    }
+
+    /// The `t(x)` of the GEV, `F(x) = exp(-t(x))`. `None` outside the support.
+    fn t(&self, x: f64) -> Option<f64> {
+        let z = (x - self.location) / self.scale;
+        if self.is_gumbel() {
+            return Some((-z).exp());
+        }
+        let arg = self.shape * z;
+        if arg <= -1.0 {
+            return None;
+        }
+        Some((-arg.ln_1p() / self.shape).exp())
+    }
+
+    // g_k = Γ(1 - kξ)
+    fn g(&self, k: f64) -> f64 {
+        gamma(1.0 - k * self.shape)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Gev {
+    fn cdf(&self, x: f64) -> f64 {
+        match self.t(x) {
+            Some(t) => (-t).exp(),
+            // below the lower bound when ξ > 0, above the upper bound when ξ < 0
+            None if self.shape > 0.0 => 0.0,
+            None => 1.0,
+        }
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        let y = -p.ln();
+        if self.is_gumbel() {
+            self.location - self.scale * y.ln()
+        } else {
+            self.location + self.scale * (y.powf(-self.shape) - 1.0) / self.shape
+        }
+    }
+}
+
+impl Min<f64> for Gev {
+    fn min(&self) -> f64 {
+        if self.shape > 0.0 && !self.is_gumbel() {
+            self.location - self.scale / self.shape
+        } else {
+            f64::NEG_INFINITY
+        }
+    }
+}
+
+impl Max<f64> for Gev {
+    fn max(&self) -> f64 {
+        if self.shape < 0.0 && !self.is_gumbel() {
+            self.location - self.scale / self.shape
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl Distribution<f64> for Gev {
+    fn mean(&self) -> Option<f64> {
+        if self.is_gumbel() {
+            Some(self.location + self.scale * EULER_MASCHERONI)
+        } else if self.shape < 1.0 {
+            Some(self.location + self.scale * (self.g(1.0) - 1.0) / self.shape)
+        } else {
+            None
+        }
+    }
+
+    fn variance(&self) -> Option<f64> {
+        if self.is_gumbel() {
+            Some(self.scale * self.scale * PI * PI / 6.0)
+        } else if self.shape < 0.5 {
+            let (g1, g2) = (self.g(1.0), self.g(2.0));
+            Some(self.scale * self.scale * (g2 - g1 * g1) / (self.shape * self.shape))
+        } else {
+            None
+        }
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + EULER_MASCHERONI * self.shape + EULER_MASCHERONI + 1.0)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        if self.is_gumbel() {
+            Some(12.0 * 6_f64.sqrt() * ZETA_3 / PI.powi(3))
+        } else if self.shape < 1.0 / 3.0 {
+            let (g1, g2, g3) = (self.g(1.0), self.g(2.0), self.g(3.0));
+            let skew = (g3 - 3.0 * g2 * g1 + 2.0 * g1.powi(3)) / (g2 - g1 * g1).powf(1.5);
+            Some(skew * self.shape.signum())
+        } else {
+            None
+        }
+    }
+}
+
+impl Continuous<f64, f64> for Gev {
+    fn pdf(&self, x: f64) -> f64 {
+        match self.t(x) {
+            Some(t) => t.powf(self.shape + 1.0) * (-t).exp() / self.scale,
+            None => 0.0,
+        }
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+/// Generalized Pareto distribution with location `μ`, scale `σ` and shape `ξ`,
+/// the limiting distribution of exceedances over a high threshold.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GeneralizedPareto {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+impl GeneralizedPareto {
+    pub fn new(
+        location: f64,
+        scale: f64,
+        shape: f64,
+    ) -> Result<GeneralizedPareto, ExtremeValueError> {
+        check_params(location, scale, shape)?;
+        Ok(GeneralizedPareto {
+            location,
+            scale,
+            shape,
+        })
+    }
+
+    fn is_exponential(&self) -> bool {
+        self.shape.abs() < SHAPE_EPS
+    }
+}
+
+impl ContinuousCDF<f64, f64> for GeneralizedPareto {
+    fn cdf(&self, x: f64) -> f64 {
+        1.0 - self.sf(x)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.location {
+            return 1.0;
+        }
+        if x >= self.max() {
+            return 0.0;
+        }
+        let z = (x - self.location) / self.scale;
+        if self.is_exponential() {
+            (-z).exp()
+        } else {
+            (-(self.shape * z).ln_1p() / self.shape).exp()
+        }
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        if self.is_exponential() {
+            self.location - self.scale * (-p).ln_1p()
+        } else {
+            self.location + self.scale * ((-p).ln_1p() * -self.shape).exp_m1() / self.shape
+        }
+    }
+}
+
+impl Min<f64> for GeneralizedPareto {
+    fn min(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Max<f64> for GeneralizedPareto {
+    fn max(&self) -> f64 {
+        if self.shape < 0.0 && !self.is_exponential() {
+            self.location - self.scale / self.shape
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl Distribution<f64> for GeneralizedPareto {
+    fn mean(&self) -> Option<f64> {
+        if self.shape < 1.0 {
+            Some(self.location + self.scale / (1.0 - self.shape))
+        } else {
+            None
+        }
+    }
+
+    fn variance(&self) -> Option<f64> {
+        if self.shape < 0.5 {
+            let a = 1.0 - self.shape;
+            Some(self.scale * self.scale / (a * a * (1.0 - 2.0 * self.shape)))
+        } else {
+            None
+        }
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + self.shape + 1.0)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        if self.shape < 1.0 / 3.0 {
+            Some(
+                2.0 * (1.0 + self.shape) * (1.0 - 2.0 * self.shape).sqrt()
+                    / (1.0 - 3.0 * self.shape),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl Continuous<f64, f64> for GeneralizedPareto {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.location || x > self.max() {
+            return 0.0;
+        }
+        let z = (x - self.location) / self.scale;
+        if self.is_exponential() {
+            (-z).exp() / self.scale
+        } else {
+            ((-1.0 / self.shape - 1.0) * (self.shape * z).ln_1p()).exp() / self.scale
+        }
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
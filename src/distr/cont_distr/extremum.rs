@@ -0,0 +1,156 @@
+use super::{Cont, has_moment, numeric_entropy, raw_moment};
+use crate::distr::numeric::{invert_cdf, moments_from_raw};
+use statrs::{
+    distribution::{Continuous, ContinuousCDF},
+    statistics::{Distribution, Max, Min},
+};
+use std::rc::Rc;
+
+/// Distribution of `min(X, Y)` or `max(X, Y)` for independent `X` and `Y`.
+///
+/// Density and CDF follow in closed form from those of `X` and `Y`, the
+/// moments and entropy are integrated numerically once.
+#[derive(Clone)]
+pub struct Extremum {
+    x: Rc<dyn Cont>,
+    y: Rc<dyn Cont>,
+    maximum: bool,
+    moments: (Option<f64>, Option<f64>, Option<f64>),
+    entropy: f64,
+}
+
+impl Extremum {
+    pub fn minimum(x: Box<dyn Cont>, y: Box<dyn Cont>) -> Extremum {
+        Extremum::new(x, y, false)
+    }
+
+    pub fn maximum(x: Box<dyn Cont>, y: Box<dyn Cont>) -> Extremum {
+        Extremum::new(x, y, true)
+    }
+
+    fn new(x: Box<dyn Cont>, y: Box<dyn Cont>, maximum: bool) -> Extremum {
+        let mut extremum = Extremum {
+            x: Rc::from(x),
+            y: Rc::from(y),
+            maximum,
+            moments: (None, None, None),
+            entropy: 0.0,
+        };
+        let raw = |k| extremum.has_moment(k).then(|| raw_moment(&extremum, k));
+        let moments = moments_from_raw([raw(1), raw(2), raw(3)]);
+        extremum.moments = moments;
+        extremum.entropy = numeric_entropy(&extremum);
+        extremum
+    }
+
+    /// Whether the `k`-th moment exists. The side the extremum is taken
+    /// towards needs light tails on both variables, the other side on one
+    /// of them. A bounded side counts as light.
+    fn has_moment(&self, k: i32) -> bool {
+        let light = |d: &dyn Cont| has_moment(d, k);
+        let bounded = |d: &dyn Cont| {
+            if self.maximum {
+                d.max().is_finite()
+            } else {
+                d.min().is_finite()
+            }
+        };
+        let (x, y) = (self.x.as_ref(), self.y.as_ref());
+        (light(x) || bounded(x)) && (light(y) || bounded(y)) && (light(x) || light(y))
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Extremum {
+    fn cdf(&self, z: f64) -> f64 {
+        let (fx, fy) = (self.x.cdf(z), self.y.cdf(z));
+        if self.maximum {
+            fx * fy
+        } else {
+            // rather than 1 - sf, which loses the lower tail
+            fx + fy - fx * fy
+        }
+    }
+
+    fn sf(&self, z: f64) -> f64 {
+        let (sx, sy) = (self.x.sf(z), self.y.sf(z));
+        if self.maximum {
+            sx + sy - sx * sy
+        } else {
+            sx * sy
+        }
+    }
+
+    /// Bracketed by the quantiles of `X` and `Y`: the maximum lies above
+    /// both at `p` and below one of them at `(1 + p) / 2`, the minimum
+    /// symmetrically.
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        let quantile = |q: f64| (self.x.inverse_cdf(q), self.y.inverse_cdf(q));
+        let (lo, hi) = if self.maximum {
+            let ((x_lo, y_lo), (x_hi, y_hi)) = (quantile(p), quantile(0.5 * (1.0 + p)));
+            (x_lo.max(y_lo), x_hi.max(y_hi))
+        } else {
+            let ((x_lo, y_lo), (x_hi, y_hi)) = (quantile(0.5 * p), quantile(p));
+            (x_lo.min(y_lo), x_hi.min(y_hi))
+        };
+        invert_cdf(|z| self.cdf(z), p, lo, hi)
+    }
+}
+
+impl Min<f64> for Extremum {
+    fn min(&self) -> f64 {
+        if self.maximum {
+            self.x.min().max(self.y.min())
+        } else {
+            self.x.min().min(self.y.min())
+        }
+    }
+}
+
+impl Max<f64> for Extremum {
+    fn max(&self) -> f64 {
+        if self.maximum {
+            self.x.max().max(self.y.max())
+        } else {
+            self.x.max().min(self.y.max())
+        }
+    }
+}
+
+impl Distribution<f64> for Extremum {
+    fn mean(&self) -> Option<f64> {
+        self.moments.0
+    }
+
+    fn variance(&self) -> Option<f64> {
+        self.moments.1
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        Some(self.entropy)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        self.moments.2
+    }
+}
+
+impl Continuous<f64, f64> for Extremum {
+    fn pdf(&self, z: f64) -> f64 {
+        let (x, y) = (self.x.as_ref(), self.y.as_ref());
+        if self.maximum {
+            x.pdf(z) * y.cdf(z) + y.pdf(z) * x.cdf(z)
+        } else {
+            x.pdf(z) * y.sf(z) + y.pdf(z) * x.sf(z)
+        }
+    }
+
+    fn ln_pdf(&self, z: f64) -> f64 {
+        self.pdf(z).ln()
+    }
+}
@@ -0,0 +1,230 @@
+use crate::distr::numeric::{EULER_MASCHERONI, invert_cdf, std_normal_cdf};
+use statrs::{
+    distribution::{Continuous, ContinuousCDF},
+    function::erf::{erf, erfc, erfc_inv},
+    statistics::{Distribution, Max, Min},
+};
+use std::f64::consts::PI;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum FirstPassageError {
+    LocationNotFinite,
+    MeanNotPositive,
+    ScaleNotPositive,
+    ShapeNotPositive,
+}
+
+impl std::fmt::Display for FirstPassageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FirstPassageError::LocationNotFinite => write!(f, "Location is NaN or infinite"),
+            FirstPassageError::MeanNotPositive => {
+                write!(f, "Mean is NaN, infinite, zero or less than zero")
+            }
+            FirstPassageError::ScaleNotPositive => {
+                write!(f, "Scale is NaN, infinite, zero or less than zero")
+            }
+            FirstPassageError::ShapeNotPositive => {
+                write!(f, "Shape is NaN, infinite, zero or less than zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FirstPassageError {}
+
+/// Mills ratio `Φ(-x) / φ(x)` for `x >= 0`, which stays finite where both
+/// `Φ(-x)` and `φ(x)` underflow.
+fn mills_ratio(x: f64) -> f64 {
+    if x < 30.0 {
+        std_normal_cdf(-x) * (2.0 * PI).sqrt() * (0.5 * x * x).exp()
+    } else {
+        let x2 = x * x;
+        (1.0 - 1.0 / x2 + 3.0 / (x2 * x2) - 15.0 / (x2 * x2 * x2)) / x
+    }
+}
+
+/// Inverse Gaussian (Wald) distribution with mean `μ` and shape `λ`, the
+/// first passage time of a Brownian motion with positive drift.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct InverseGaussian {
+    mean: f64,
+    shape: f64,
+}
+
+impl InverseGaussian {
+    pub fn new(mean: f64, shape: f64) -> Result<InverseGaussian, FirstPassageError> {
+        if !mean.is_finite() || mean <= 0.0 {
+            return Err(FirstPassageError::MeanNotPositive);
+        }
+        if !shape.is_finite() || shape <= 0.0 {
+            return Err(FirstPassageError::ShapeNotPositive);
+        }
+        Ok(InverseGaussian { mean, shape })
+    }
+
+    /// The two normal arguments `sqrt(λ/x) (x/μ ∓ 1)` of the CDF and the second
+    /// term `exp(2λ/μ) Φ(-b)`, written as `φ(a) R(b)` so it cannot overflow.
+    fn cdf_terms(&self, x: f64) -> (f64, f64) {
+        let r = (self.shape / x).sqrt();
+        let a = r * (x / self.mean - 1.0);
+        let b = r * (x / self.mean + 1.0);
+        let reflected = (-0.5 * a * a).exp() / (2.0 * PI).sqrt() * mills_ratio(b);
+        (a, reflected)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for InverseGaussian {
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let (a, reflected) = self.cdf_terms(x);
+        (std_normal_cdf(a) + reflected).clamp(0.0, 1.0)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 1.0;
+        }
+        let (a, reflected) = self.cdf_terms(x);
+        (std_normal_cdf(-a) - reflected).clamp(0.0, 1.0)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        let sd = (self.mean.powi(3) / self.shape).sqrt();
+        invert_cdf(
+            |x| self.cdf(x),
+            p,
+            (self.mean - 4.0 * sd).max(0.0),
+            self.mean + 4.0 * sd,
+        )
+    }
+}
+
+impl Min<f64> for InverseGaussian {
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for InverseGaussian {
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for InverseGaussian {
+    fn mean(&self) -> Option<f64> {
+        Some(self.mean)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        Some(self.mean.powi(3) / self.shape)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        Some(3.0 * (self.mean / self.shape).sqrt())
+    }
+}
+
+impl Continuous<f64, f64> for InverseGaussian {
+    fn pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let d = x - self.mean;
+        (self.shape / (2.0 * PI * x.powi(3))).sqrt()
+            * (-self.shape * d * d / (2.0 * self.mean * self.mean * x)).exp()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+/// Lévy distribution with location `μ` and scale `c`, the first passage time
+/// of a Brownian motion without drift. Its tail is heavier than Cauchy's and
+/// it has no finite moments.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Levy {
+    location: f64,
+    scale: f64,
+}
+
+impl Levy {
+    pub fn new(location: f64, scale: f64) -> Result<Levy, FirstPassageError> {
+        if !location.is_finite() {
+            return Err(FirstPassageError::LocationNotFinite);
+        }
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(FirstPassageError::ScaleNotPositive);
+        }
+        Ok(Levy { location, scale })
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Levy {
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.location {
+            return 0.0;
+        }
+        erfc((self.scale / (2.0 * (x - self.location))).sqrt())
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.location {
+            return 1.0;
+        }
+        erf((self.scale / (2.0 * (x - self.location))).sqrt())
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        let z = erfc_inv(p);
+        self.location + self.scale / (2.0 * z * z)
+    }
+}
+
+impl Min<f64> for Levy {
+    fn min(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Max<f64> for Levy {
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Levy {
+    fn entropy(&self) -> Option<f64> {
+        Some((1.0 + 3.0 * EULER_MASCHERONI + (16.0 * PI * self.scale * self.scale).ln()) / 2.0)
+    }
+}
+
+impl Continuous<f64, f64> for Levy {
+    fn pdf(&self, x: f64) -> f64 {
+        if x <= self.location {
+            return 0.0;
+        }
+        let d = x - self.location;
+        (self.scale / (2.0 * PI)).sqrt() * (-self.scale / (2.0 * d)).exp() / d.powf(1.5)
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
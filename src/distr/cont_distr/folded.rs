@@ -0,0 +1,229 @@
+use crate::distr::numeric::{invert_cdf, std_normal_cdf};
+use statrs::{
+    distribution::{Continuous, ContinuousCDF},
+    statistics::{Distribution, Max, Min},
+};
+use std::f64::consts::PI;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum FoldedError {
+    MeanNotFinite,
+    ScaleNotPositive,
+}
+
+impl std::fmt::Display for FoldedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FoldedError::MeanNotFinite => write!(f, "Mean is NaN or infinite"),
+            FoldedError::ScaleNotPositive => {
+                write!(f, "Scale is NaN, infinite, zero or less than zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FoldedError {}
+
+fn check_scale(scale: f64) -> Result<(), FoldedError> {
+    if !scale.is_finite() || scale <= 0.0 {
+        return Err(FoldedError::ScaleNotPositive);
+    }
+    Ok(())
+}
+
+/// Folded normal distribution, the absolute value of a normal variable with
+/// mean `μ` and standard deviation `σ`. `μ = 0` is the half-normal.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FoldedNormal {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl FoldedNormal {
+    pub fn new(mean: f64, std_dev: f64) -> Result<FoldedNormal, FoldedError> {
+        if !mean.is_finite() {
+            return Err(FoldedError::MeanNotFinite);
+        }
+        check_scale(std_dev)?;
+        Ok(FoldedNormal { mean, std_dev })
+    }
+
+    /// Half-normal distribution with scale `σ`, the absolute value of a
+    /// centered normal variable.
+    pub fn half(scale: f64) -> Result<FoldedNormal, FoldedError> {
+        FoldedNormal::new(0.0, scale)
+    }
+
+    /// `sqrt(2/π) exp(-μ²/2σ²)`, which shows up in every raw moment.
+    fn fold_term(&self) -> f64 {
+        let z = self.mean / self.std_dev;
+        (2.0 / PI).sqrt() * (-0.5 * z * z).exp()
+    }
+
+    /// `1 - 2Φ(-μ/σ)`, the weight of the unfolded mean in the raw moments.
+    fn sign_term(&self) -> f64 {
+        1.0 - 2.0 * std_normal_cdf(-self.mean / self.std_dev)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for FoldedNormal {
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let s = self.std_dev;
+        (std_normal_cdf((x - self.mean) / s) - std_normal_cdf((-x - self.mean) / s)).max(0.0)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 1.0;
+        }
+        let s = self.std_dev;
+        (std_normal_cdf((self.mean - x) / s) + std_normal_cdf((-x - self.mean) / s)).min(1.0)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        let hi = self.mean.abs() + 4.0 * self.std_dev;
+        invert_cdf(|x| self.cdf(x), p, 0.0, hi)
+    }
+}
+
+impl Min<f64> for FoldedNormal {
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for FoldedNormal {
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for FoldedNormal {
+    fn mean(&self) -> Option<f64> {
+        Some(self.std_dev * self.fold_term() + self.mean * self.sign_term())
+    }
+
+    fn variance(&self) -> Option<f64> {
+        let m = self.mean()?;
+        Some(self.mean * self.mean + self.std_dev * self.std_dev - m * m)
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        // the density is flat at 0, so the trapezoidal rule converges quickly
+        let n = 4000;
+        let h = (self.mean.abs() + 12.0 * self.std_dev) / n as f64;
+        let entropy = (0..=n)
+            .map(|i| {
+                let f = self.pdf(i as f64 * h);
+                let w = if i == 0 || i == n { 0.5 } else { 1.0 };
+                if f > 0.0 { -w * f * f.ln() * h } else { 0.0 }
+            })
+            .sum();
+        Some(entropy)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        let (mu, s2) = (self.mean, self.std_dev * self.std_dev);
+        let m1 = self.mean()?;
+        let var = self.variance()?;
+        let m3 = (mu.powi(3) + 3.0 * mu * s2) * self.sign_term()
+            + self.std_dev * self.fold_term() * (mu * mu + 2.0 * s2);
+        Some((m3 - 3.0 * m1 * var - m1.powi(3)) / var.powf(1.5))
+    }
+}
+
+impl Continuous<f64, f64> for FoldedNormal {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+        let s = self.std_dev;
+        let (a, b) = ((x - self.mean) / s, (x + self.mean) / s);
+        ((-0.5 * a * a).exp() + (-0.5 * b * b).exp()) / (s * (2.0 * PI).sqrt())
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+/// Half-Cauchy distribution with scale `σ`, the absolute value of a centered
+/// Cauchy variable. Like the Cauchy it has no finite moments.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct HalfCauchy {
+    scale: f64,
+}
+
+impl HalfCauchy {
+    pub fn new(scale: f64) -> Result<HalfCauchy, FoldedError> {
+        check_scale(scale)?;
+        Ok(HalfCauchy { scale })
+    }
+}
+
+impl ContinuousCDF<f64, f64> for HalfCauchy {
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        2.0 / PI * (x / self.scale).atan()
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 1.0;
+        }
+        2.0 / PI * (self.scale / x).atan()
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        self.scale * (PI / 2.0 * p).tan()
+    }
+}
+
+impl Min<f64> for HalfCauchy {
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for HalfCauchy {
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for HalfCauchy {
+    fn entropy(&self) -> Option<f64> {
+        Some((2.0 * PI * self.scale).ln())
+    }
+}
+
+impl Continuous<f64, f64> for HalfCauchy {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+        let z = x / self.scale;
+        2.0 / (PI * self.scale * (1.0 + z * z))
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
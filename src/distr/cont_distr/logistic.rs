@@ -0,0 +1,213 @@
+use crate::distr::numeric::moments_from_raw;
+use statrs::{
+    distribution::{Continuous, ContinuousCDF},
+    statistics::{Distribution, Max, Min},
+};
+use std::f64::consts::PI;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum LogisticError {
+    LocationNotFinite,
+    ScaleNotPositive,
+    ShapeNotPositive,
+}
+
+impl std::fmt::Display for LogisticError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogisticError::LocationNotFinite => write!(f, "Location is NaN or infinite"),
+            LogisticError::ScaleNotPositive => {
+                write!(f, "Scale is NaN, infinite, zero or less than zero")
+            }
+            LogisticError::ShapeNotPositive => {
+                write!(f, "Shape is NaN, infinite, zero or less than zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LogisticError {}
+
+/// Logistic distribution with location `μ` and scale `s`,
+/// `F(x) = 1 / (1 + exp(-(x - μ) / s))`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Logistic {
+    location: f64,
+    scale: f64,
+}
+
+impl Logistic {
+    pub fn new(location: f64, scale: f64) -> Result<Logistic, LogisticError> {
+        if !location.is_finite() {
+            return Err(LogisticError::LocationNotFinite);
+        }
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(LogisticError::ScaleNotPositive);
+        }
+        Ok(Logistic { location, scale })
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Logistic {
+    fn cdf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        1.0 / (1.0 + (-z).exp())
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        1.0 / (1.0 + z.exp())
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        self.location + self.scale * (p / (1.0 - p)).ln()
+    }
+}
+
+impl Min<f64> for Logistic {
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for Logistic {
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Logistic {
+    fn mean(&self) -> Option<f64> {
+        Some(self.location)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        Some(self.scale * self.scale * PI * PI / 3.0)
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + 2.0)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        Some(0.0)
+    }
+}
+
+impl Continuous<f64, f64> for Logistic {
+    fn pdf(&self, x: f64) -> f64 {
+        // symmetric, evaluating at -|z| avoids overflow of exp in the tails
+        let z = -((x - self.location) / self.scale).abs();
+        let e = z.exp();
+        e / (self.scale * (1.0 + e) * (1.0 + e))
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+/// Log-logistic (Fisk) distribution with scale `α` and shape `β`,
+/// `F(x) = 1 / (1 + (x / α)^-β)` for `x > 0`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct LogLogistic {
+    scale: f64,
+    shape: f64,
+}
+
+impl LogLogistic {
+    pub fn new(scale: f64, shape: f64) -> Result<LogLogistic, LogisticError> {
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(LogisticError::ScaleNotPositive);
+        }
+        if !shape.is_finite() || shape <= 0.0 {
+            return Err(LogisticError::ShapeNotPositive);
+        }
+        Ok(LogLogistic { scale, shape })
+    }
+
+    /// `E[X^k] = α^k (kπ/β) / sin(kπ/β)`, finite only for `k < β`.
+    fn raw_moment(&self, k: f64) -> Option<f64> {
+        if k >= self.shape {
+            return None;
+        }
+        let b = k * PI / self.shape;
+        Some(self.scale.powf(k) * b / b.sin())
+    }
+}
+
+impl ContinuousCDF<f64, f64> for LogLogistic {
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        1.0 / (1.0 + (x / self.scale).powf(-self.shape))
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 1.0;
+        }
+        1.0 / (1.0 + (x / self.scale).powf(self.shape))
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        self.scale * (p / (1.0 - p)).powf(1.0 / self.shape)
+    }
+}
+
+impl Min<f64> for LogLogistic {
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for LogLogistic {
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for LogLogistic {
+    fn mean(&self) -> Option<f64> {
+        self.raw_moment(1.0)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        moments_from_raw([1.0, 2.0, 3.0].map(|r| self.raw_moment(r))).1
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        Some((self.scale / self.shape).ln() + 2.0)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        moments_from_raw([1.0, 2.0, 3.0].map(|r| self.raw_moment(r))).2
+    }
+}
+
+impl Continuous<f64, f64> for LogLogistic {
+    fn pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let z = (x / self.scale).powf(self.shape);
+        self.shape / x * z / ((1.0 + z) * (1.0 + z))
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
@@ -0,0 +1,210 @@
+use super::{Circular, Cont};
+use crate::distr::numeric::{QUANTILE_LEVELS, integrate_pieces, invert_cdf, mixture_moments};
+use statrs::{
+    distribution::{Continuous, ContinuousCDF},
+    statistics::{Distribution, Max, Min},
+};
+use std::rc::Rc;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum MixtureError {
+    NoComponents,
+    WeightInvalid,
+    WeightsZero,
+}
+
+impl std::fmt::Display for MixtureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MixtureError::NoComponents => write!(f, "The mixture has no components"),
+            MixtureError::WeightInvalid => {
+                write!(f, "A weight is NaN, infinite or less than zero")
+            }
+            MixtureError::WeightsZero => write!(f, "All weights are zero"),
+        }
+    }
+}
+
+impl std::error::Error for MixtureError {}
+
+/// Checks the weights of a mixture and normalizes them to sum to one.
+pub(crate) fn normalize_weights(weights: &[f64]) -> Result<Vec<f64>, MixtureError> {
+    if weights.is_empty() {
+        return Err(MixtureError::NoComponents);
+    }
+    if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+        return Err(MixtureError::WeightInvalid);
+    }
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return Err(MixtureError::WeightsZero);
+    }
+    Ok(weights.iter().map(|w| w / total).collect())
+}
+
+/// Finite mixture `Σ wᵢ Fᵢ` of continuous distributions, the weights are
+/// normalized to sum to one. It is circular if all of its components are.
+///
+/// The entropy has no closed form and is integrated once, when the mixture is
+/// built.
+#[derive(Clone)]
+pub struct Mixture {
+    weights: Vec<f64>,
+    components: Vec<Rc<dyn Cont>>,
+    moments: (Option<f64>, Option<f64>, Option<f64>),
+    entropy: f64,
+}
+
+impl Mixture {
+    pub fn new(components: Vec<(f64, Box<dyn Cont>)>) -> Result<Mixture, MixtureError> {
+        let (weights, components): (Vec<_>, Vec<_>) = components.into_iter().unzip();
+        let mut mixture = Mixture {
+            weights: normalize_weights(&weights)?,
+            components: components.into_iter().map(Rc::from).collect(),
+            moments: (None, None, None),
+            entropy: 0.0,
+        };
+        mixture.moments = mixture.moments();
+        mixture.entropy = mixture.integrate_entropy();
+        Ok(mixture)
+    }
+
+    /// The components with their normalized weights.
+    pub fn components(&self) -> impl Iterator<Item = (f64, &dyn Cont)> {
+        self.weights
+            .iter()
+            .copied()
+            .zip(self.components.iter().map(|c| c.as_ref()))
+    }
+
+    fn weighted(&self, f: impl Fn(&dyn Cont) -> f64) -> f64 {
+        self.components().map(|(w, c)| w * f(c)).sum()
+    }
+
+    fn moments(&self) -> (Option<f64>, Option<f64>, Option<f64>) {
+        let parts: Vec<(f64, &dyn Distribution<f64>)> = self
+            .components()
+            .map(|(w, c)| (w, c as &dyn Distribution<f64>))
+            .collect();
+        mixture_moments(&parts)
+    }
+
+    fn integrate_entropy(&self) -> f64 {
+        // split at the quantiles of every component so none of them is missed
+        let points = self
+            .components
+            .iter()
+            .flat_map(|c| {
+                QUANTILE_LEVELS
+                    .iter()
+                    .map(|&q| c.inverse_cdf(q))
+                    .chain([c.min(), c.max()])
+            })
+            .collect();
+        integrate_pieces(
+            |x| {
+                let f = self.pdf(x);
+                if f > 0.0 { -f * f.ln() } else { 0.0 }
+            },
+            points,
+        )
+    }
+
+    pub(crate) fn is_circular(&self) -> bool {
+        self.components.iter().all(|c| c.as_circular().is_some())
+    }
+
+    // Σ wᵢ Rᵢ (cos μᵢ, sin μᵢ) over the circular components
+    fn resultant(&self) -> (f64, f64) {
+        self.components()
+            .filter_map(|(w, c)| c.as_circular().map(|c| (w, c)))
+            .map(|(w, c)| {
+                let (r, mu) = (c.mean_resultant_length(), c.mean_direction());
+                (w * r * mu.cos(), w * r * mu.sin())
+            })
+            .fold((0.0, 0.0), |(x, y), (dx, dy)| (x + dx, y + dy))
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Mixture {
+    fn cdf(&self, x: f64) -> f64 {
+        self.weighted(|c| c.cdf(x)).clamp(0.0, 1.0)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        self.weighted(|c| c.sf(x)).clamp(0.0, 1.0)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        // the quantile lies between the smallest and largest component quantile
+        let quantiles = self.components.iter().map(|c| c.inverse_cdf(p));
+        let lo = quantiles.clone().fold(f64::INFINITY, f64::min);
+        let hi = quantiles.fold(f64::NEG_INFINITY, f64::max);
+        invert_cdf(|x| self.cdf(x), p, lo, hi)
+    }
+}
+
+impl Min<f64> for Mixture {
+    fn min(&self) -> f64 {
+        self.components
+            .iter()
+            .map(|c| c.min())
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+impl Max<f64> for Mixture {
+    fn max(&self) -> f64 {
+        self.components
+            .iter()
+            .map(|c| c.max())
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+}
+
+impl Distribution<f64> for Mixture {
+    fn mean(&self) -> Option<f64> {
+        self.moments.0
+    }
+
+    fn variance(&self) -> Option<f64> {
+        self.moments.1
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        Some(self.entropy)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        self.moments.2
+    }
+}
+
+impl Continuous<f64, f64> for Mixture {
+    fn pdf(&self, x: f64) -> f64 {
+        self.weighted(|c| c.pdf(x))
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+impl Circular for Mixture {
+    /// Direction of the weighted sum of the mean resultant vectors.
+    fn mean_direction(&self) -> f64 {
+        let (x, y) = self.resultant();
+        y.atan2(x)
+    }
+
+    fn mean_resultant_length(&self) -> f64 {
+        let (x, y) = self.resultant();
+        x.hypot(y)
+    }
+}
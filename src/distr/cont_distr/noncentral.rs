@@ -0,0 +1,453 @@
+use crate::distr::numeric::{
+    invert_cdf, moments_from_raw, poisson_gamma_lr, poisson_mixture, std_normal_cdf,
+};
+use statrs::{
+    distribution::{Continuous, ContinuousCDF},
+    function::{
+        beta::{beta_reg, ln_beta},
+        gamma::{gamma_ur, ln_gamma},
+    },
+    statistics::{Distribution, Max, Min},
+};
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum NoncentralError {
+    FreedomNotPositive,
+    NoncentralityNegative,
+    NoncentralityNotFinite,
+}
+
+impl std::fmt::Display for NoncentralError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NoncentralError::FreedomNotPositive => {
+                write!(f, "Freedom is NaN, infinite, zero or less than zero")
+            }
+            NoncentralError::NoncentralityNegative => {
+                write!(f, "Noncentrality is NaN, infinite or less than zero")
+            }
+            NoncentralError::NoncentralityNotFinite => {
+                write!(f, "Noncentrality is NaN or infinite")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NoncentralError {}
+
+fn check_freedom(freedom: f64) -> Result<(), NoncentralError> {
+    if !freedom.is_finite() || freedom <= 0.0 {
+        return Err(NoncentralError::FreedomNotPositive);
+    }
+    Ok(())
+}
+
+fn check_noncentrality(lambda: f64) -> Result<(), NoncentralError> {
+    if !lambda.is_finite() || lambda < 0.0 {
+        return Err(NoncentralError::NoncentralityNegative);
+    }
+    Ok(())
+}
+
+/// Density of `Gamma(a, 1)` at `y`, with the limits at `y = 0`.
+fn gamma_density(a: f64, y: f64) -> f64 {
+    if y <= 0.0 {
+        return if a < 1.0 {
+            f64::INFINITY
+        } else if a == 1.0 {
+            1.0
+        } else {
+            0.0
+        };
+    }
+    ((a - 1.0) * y.ln() - y - ln_gamma(a)).exp()
+}
+
+/// Density of `Beta(a, b)` at `x`, with the limits at `x = 0`.
+fn beta_density(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return if a < 1.0 {
+            f64::INFINITY
+        } else if a == 1.0 {
+            b
+        } else {
+            0.0
+        };
+    }
+    ((a - 1.0) * x.ln() + (b - 1.0) * (-x).ln_1p() - ln_beta(a, b)).exp()
+}
+
+/// Raw moments `E[X^k]`, `k <= 3`, of the noncentral chi-squared distribution
+/// from its cumulants `k + λ`, `2(k + 2λ)` and `8(k + 3λ)`.
+fn chi_squared_raw_moment(freedom: f64, lambda: f64, k: i32) -> f64 {
+    let (c1, c2, c3) = (
+        freedom + lambda,
+        2.0 * (freedom + 2.0 * lambda),
+        8.0 * (freedom + 3.0 * lambda),
+    );
+    match k {
+        1 => c1,
+        2 => c2 + c1 * c1,
+        _ => c3 + 3.0 * c2 * c1 + c1.powi(3),
+    }
+}
+
+/// Noncentral chi-squared distribution with `k` degrees of freedom and
+/// noncentrality `λ`, the sum of `k` squared unit normals whose means have
+/// squares summing to `λ`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct NoncentralChiSquared {
+    freedom: f64,
+    lambda: f64,
+}
+
+impl NoncentralChiSquared {
+    pub fn new(freedom: f64, lambda: f64) -> Result<NoncentralChiSquared, NoncentralError> {
+        check_freedom(freedom)?;
+        check_noncentrality(lambda)?;
+        Ok(NoncentralChiSquared { freedom, lambda })
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NoncentralChiSquared {
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        poisson_gamma_lr(self.lambda / 2.0, self.freedom / 2.0, x / 2.0)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 1.0;
+        }
+        poisson_mixture(self.lambda / 2.0, |j| {
+            gamma_ur(self.freedom / 2.0 + j, x / 2.0)
+        })
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        let mean = self.freedom + self.lambda;
+        let sd = (2.0 * (self.freedom + 2.0 * self.lambda)).sqrt();
+        invert_cdf(
+            |x| self.cdf(x),
+            p,
+            (mean - 4.0 * sd).max(0.0),
+            mean + 4.0 * sd,
+        )
+    }
+}
+
+impl Min<f64> for NoncentralChiSquared {
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for NoncentralChiSquared {
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for NoncentralChiSquared {
+    fn mean(&self) -> Option<f64> {
+        Some(self.freedom + self.lambda)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        Some(2.0 * (self.freedom + 2.0 * self.lambda))
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        let (k, l) = (self.freedom, self.lambda);
+        Some(2_f64.powf(1.5) * (k + 3.0 * l) / (k + 2.0 * l).powf(1.5))
+    }
+}
+
+impl Continuous<f64, f64> for NoncentralChiSquared {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+        poisson_mixture(self.lambda / 2.0, |j| {
+            0.5 * gamma_density(self.freedom / 2.0 + j, x / 2.0)
+        })
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+/// Noncentral Student's t distribution with `ν` degrees of freedom and
+/// noncentrality `δ`, the distribution of `(Z + δ) / sqrt(V / ν)`.
+///
+/// The CDF uses Lenth's series of incomplete beta functions. For `t >= 0`
+/// the lower and upper tails are summed separately and negative `t` is
+/// reflected via `F(t; ν, δ) = 1 - F(-t; ν, -δ)`, so both tails keep their
+/// relative accuracy.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct NoncentralStudentsT {
+    freedom: f64,
+    delta: f64,
+}
+
+impl NoncentralStudentsT {
+    pub fn new(freedom: f64, delta: f64) -> Result<NoncentralStudentsT, NoncentralError> {
+        check_freedom(freedom)?;
+        if !delta.is_finite() {
+            return Err(NoncentralError::NoncentralityNotFinite);
+        }
+        Ok(NoncentralStudentsT { freedom, delta })
+    }
+
+    // weight of the odd terms relative to the Poisson weight of the even ones,
+    // δ / sqrt(2) Γ(j + 1) / Γ(j + 3/2)
+    fn odd_weight(delta: f64, j: f64) -> f64 {
+        delta * FRAC_1_SQRT_2 * (ln_gamma(j + 1.0) - ln_gamma(j + 1.5)).exp()
+    }
+
+    /// `P(T <= t)` for `t >= 0`.
+    fn lower(&self, t: f64, delta: f64) -> f64 {
+        let nu = self.freedom / 2.0;
+        let x = t * t / (t * t + self.freedom);
+        let sum = poisson_mixture(delta * delta / 2.0, |j| {
+            beta_reg(j + 0.5, nu, x) + Self::odd_weight(delta, j) * beta_reg(j + 1.0, nu, x)
+        });
+        (std_normal_cdf(-delta) + 0.5 * sum).clamp(0.0, 1.0)
+    }
+
+    /// `P(T > t)` for `t >= 0`.
+    fn upper(&self, t: f64, delta: f64) -> f64 {
+        let nu = self.freedom / 2.0;
+        let y = self.freedom / (t * t + self.freedom);
+        let sum = poisson_mixture(delta * delta / 2.0, |j| {
+            beta_reg(nu, j + 0.5, y) + Self::odd_weight(delta, j) * beta_reg(nu, j + 1.0, y)
+        });
+        (0.5 * sum).clamp(0.0, 1.0)
+    }
+
+    /// Density for `t >= 0`, the derivative of the series in `lower`.
+    fn density(&self, t: f64, delta: f64) -> f64 {
+        let nu = self.freedom;
+        if t == 0.0 {
+            return (ln_gamma((nu + 1.0) / 2.0) - ln_gamma(nu / 2.0) - delta * delta / 2.0).exp()
+                / (PI * nu).sqrt();
+        }
+        let x = t * t / (t * t + nu);
+        let dx = 2.0 * t * nu / (t * t + nu).powi(2);
+        let sum = poisson_mixture(delta * delta / 2.0, |j| {
+            beta_density(j + 0.5, nu / 2.0, x)
+                + Self::odd_weight(delta, j) * beta_density(j + 1.0, nu / 2.0, x)
+        });
+        (0.5 * sum * dx).max(0.0)
+    }
+
+    /// `E[T^k] = (ν/2)^(k/2) Γ((ν - k)/2) / Γ(ν/2) E[(Z + δ)^k]`, finite for `ν > k`.
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        let (nu, d) = (self.freedom, self.delta);
+        if nu <= k as f64 {
+            return None;
+        }
+        let normal = match k {
+            1 => d,
+            2 => 1.0 + d * d,
+            _ => d.powi(3) + 3.0 * d,
+        };
+        let half = nu / 2.0;
+        Some(
+            half.powf(k as f64 / 2.0)
+                * (ln_gamma(half - k as f64 / 2.0) - ln_gamma(half)).exp()
+                * normal,
+        )
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NoncentralStudentsT {
+    fn cdf(&self, x: f64) -> f64 {
+        if x >= 0.0 {
+            self.lower(x, self.delta)
+        } else {
+            self.upper(-x, -self.delta)
+        }
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x >= 0.0 {
+            self.upper(x, self.delta)
+        } else {
+            self.lower(-x, -self.delta)
+        }
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        invert_cdf(|x| self.cdf(x), p, self.delta - 4.0, self.delta + 4.0)
+    }
+}
+
+impl Min<f64> for NoncentralStudentsT {
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for NoncentralStudentsT {
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for NoncentralStudentsT {
+    fn mean(&self) -> Option<f64> {
+        self.raw_moment(1)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        moments_from_raw([1, 2, 3].map(|k| self.raw_moment(k))).1
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        moments_from_raw([1, 2, 3].map(|k| self.raw_moment(k))).2
+    }
+}
+
+impl Continuous<f64, f64> for NoncentralStudentsT {
+    fn pdf(&self, x: f64) -> f64 {
+        if x >= 0.0 {
+            self.density(x, self.delta)
+        } else {
+            self.density(-x, -self.delta)
+        }
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+/// Noncentral F distribution with `d1` and `d2` degrees of freedom and
+/// noncentrality `λ`, the ratio of a noncentral and a central chi-squared
+/// variable each divided by its degrees of freedom.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct NoncentralFisherSnedecor {
+    freedom_1: f64,
+    freedom_2: f64,
+    lambda: f64,
+}
+
+impl NoncentralFisherSnedecor {
+    pub fn new(
+        freedom_1: f64,
+        freedom_2: f64,
+        lambda: f64,
+    ) -> Result<NoncentralFisherSnedecor, NoncentralError> {
+        check_freedom(freedom_1)?;
+        check_freedom(freedom_2)?;
+        check_noncentrality(lambda)?;
+        Ok(NoncentralFisherSnedecor {
+            freedom_1,
+            freedom_2,
+            lambda,
+        })
+    }
+
+    /// `E[X^k] = (d2/d1)^k E[χ'²(d1, λ)^k] E[χ²(d2)^-k]`, finite for `d2 > 2k`.
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        let (d1, d2) = (self.freedom_1, self.freedom_2);
+        if d2 <= 2.0 * k as f64 {
+            return None;
+        }
+        let inverse_chi =
+            (ln_gamma(d2 / 2.0 - k as f64) - ln_gamma(d2 / 2.0)).exp() / 2_f64.powi(k);
+        Some((d2 / d1).powi(k) * chi_squared_raw_moment(d1, self.lambda, k) * inverse_chi)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NoncentralFisherSnedecor {
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let (d1, d2) = (self.freedom_1, self.freedom_2);
+        let u = d1 * x / (d1 * x + d2);
+        poisson_mixture(self.lambda / 2.0, |j| beta_reg(d1 / 2.0 + j, d2 / 2.0, u)).clamp(0.0, 1.0)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 1.0;
+        }
+        let (d1, d2) = (self.freedom_1, self.freedom_2);
+        let v = d2 / (d1 * x + d2);
+        poisson_mixture(self.lambda / 2.0, |j| beta_reg(d2 / 2.0, d1 / 2.0 + j, v)).clamp(0.0, 1.0)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        let guess = 1.0 + self.lambda / self.freedom_1;
+        invert_cdf(|x| self.cdf(x), p, 0.0, 2.0 * guess)
+    }
+}
+
+impl Min<f64> for NoncentralFisherSnedecor {
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for NoncentralFisherSnedecor {
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for NoncentralFisherSnedecor {
+    fn mean(&self) -> Option<f64> {
+        self.raw_moment(1)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        moments_from_raw([1, 2, 3].map(|k| self.raw_moment(k))).1
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        moments_from_raw([1, 2, 3].map(|k| self.raw_moment(k))).2
+    }
+}
+
+impl Continuous<f64, f64> for NoncentralFisherSnedecor {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+        let (d1, d2) = (self.freedom_1, self.freedom_2);
+        let u = d1 * x / (d1 * x + d2);
+        let du = d1 * d2 / (d1 * x + d2).powi(2);
+        poisson_mixture(self.lambda / 2.0, |j| {
+            beta_density(d1 / 2.0 + j, d2 / 2.0, u)
+        }) * du
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
@@ -0,0 +1,159 @@
+use super::{Cont, has_moment, numeric_entropy, raw_moment};
+use crate::distr::numeric::moments_from_raw;
+use statrs::{
+    distribution::{Beta, Continuous, ContinuousCDF},
+    function::beta::ln_beta,
+    statistics::{Distribution, Max, Min},
+};
+use std::rc::Rc;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum OrderStatisticError {
+    SampleEmpty,
+    RankOutOfRange,
+}
+
+impl std::fmt::Display for OrderStatisticError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OrderStatisticError::SampleEmpty => write!(f, "Sample size must be at least 1"),
+            OrderStatisticError::RankOutOfRange => {
+                write!(f, "Rank must lie between 1 and the sample size")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OrderStatisticError {}
+
+/// The two Beta distributions behind the `k`-th smallest of `n` draws: that
+/// of `F(X₍ₖ₎)`, `Beta(k, n - k + 1)`, and that of `S(X₍ₖ₎)`, the mirrored
+/// `Beta(n - k + 1, k)`.
+pub(crate) fn rank_betas(n: u64, k: u64) -> Result<(Beta, Beta), OrderStatisticError> {
+    if n == 0 {
+        return Err(OrderStatisticError::SampleEmpty);
+    }
+    if k == 0 || k > n {
+        return Err(OrderStatisticError::RankOutOfRange);
+    }
+    let (a, b) = (k as f64, (n - k + 1) as f64);
+    let beta = |a, b| Beta::new(a, b).expect("shapes are at least 1");
+    Ok((beta(a, b), beta(b, a)))
+}
+
+/// Distribution of the `k`-th smallest of `n` independent draws of a
+/// distribution, `k = 1` being the sample minimum and `k = n` the maximum.
+///
+/// The CDF is the regularized incomplete beta function `I_F(x)(k, n - k + 1)`
+/// and the quantiles are those of the original distribution at the quantiles
+/// of `Beta(k, n - k + 1)`. Moments and entropy are integrated numerically
+/// once, moments only where the original distribution has them.
+#[derive(Clone)]
+pub struct OrderStatistic {
+    n: u64,
+    k: u64,
+    lower: Beta,
+    upper: Beta,
+    distr: Rc<dyn Cont>,
+    moments: (Option<f64>, Option<f64>, Option<f64>),
+    entropy: f64,
+}
+
+impl OrderStatistic {
+    pub fn new(
+        distr: Box<dyn Cont>,
+        n: u64,
+        k: u64,
+    ) -> Result<OrderStatistic, OrderStatisticError> {
+        let (lower, upper) = rank_betas(n, k)?;
+        let mut order = OrderStatistic {
+            n,
+            k,
+            lower,
+            upper,
+            distr: Rc::from(distr),
+            moments: (None, None, None),
+            entropy: 0.0,
+        };
+        let raw = |r| has_moment(order.distr.as_ref(), r).then(|| raw_moment(&order, r));
+        let moments = moments_from_raw([raw(1), raw(2), raw(3)]);
+        order.moments = moments;
+        order.entropy = numeric_entropy(&order);
+        Ok(order)
+    }
+
+    /// Logarithm of `F(x)^(k-1) S(x)^(n-k) / B(k, n - k + 1)`, the factor
+    /// the density of the original distribution is scaled by. The binomial
+    /// coefficient in it alone overflows for samples of a few hundred.
+    fn ln_weight(&self, x: f64) -> f64 {
+        let (n, k) = (self.n as f64, self.k as f64);
+        // a zero power of a vanishing tail is one, not NaN
+        let power = |p: f64, e: f64| if e == 0.0 { 0.0 } else { e * p.ln() };
+        power(self.distr.cdf(x), k - 1.0) + power(self.distr.sf(x), n - k) - ln_beta(k, n - k + 1.0)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for OrderStatistic {
+    fn cdf(&self, x: f64) -> f64 {
+        self.lower.cdf(self.distr.cdf(x))
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        self.upper.cdf(self.distr.sf(x))
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        self.distr.inverse_cdf(self.lower.inverse_cdf(p))
+    }
+}
+
+impl Min<f64> for OrderStatistic {
+    fn min(&self) -> f64 {
+        self.distr.min()
+    }
+}
+
+impl Max<f64> for OrderStatistic {
+    fn max(&self) -> f64 {
+        self.distr.max()
+    }
+}
+
+impl Distribution<f64> for OrderStatistic {
+    fn mean(&self) -> Option<f64> {
+        self.moments.0
+    }
+
+    fn variance(&self) -> Option<f64> {
+        self.moments.1
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        Some(self.entropy)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        self.moments.2
+    }
+}
+
+impl Continuous<f64, f64> for OrderStatistic {
+    fn pdf(&self, x: f64) -> f64 {
+        let f = self.distr.pdf(x);
+        if f > 0.0 {
+            f * self.ln_weight(x).exp()
+        } else {
+            0.0
+        }
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.distr.ln_pdf(x) + self.ln_weight(x)
+    }
+}
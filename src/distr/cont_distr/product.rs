@@ -0,0 +1,227 @@
+use super::{Cont, Table, has_moment, raw_moment};
+use crate::distr::numeric::{QUANTILE_LEVELS, integrate_pieces, moments_from_raw};
+use statrs::{
+    distribution::{Continuous, ContinuousCDF},
+    statistics::{Distribution, Max, Min},
+};
+
+/// Distribution of the product `X·Y` or the ratio `X/Y` of independent `X`
+/// and `Y`.
+///
+/// Density and CDF are integrals over one of the variables, tabulated and
+/// interpolated in between, tails beyond a thousand interquartile ranges are
+/// left out. The moments are `E[X^k] E[Y^±k]`, each integrated numerically.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Product {
+    table: Table,
+    moments: (Option<f64>, Option<f64>, Option<f64>),
+}
+
+impl Product {
+    pub fn new(x: &dyn Cont, y: &dyn Cont) -> Product {
+        let support = interval_product((x.min(), x.max()), (y.min(), y.max()));
+        let raw =
+            |k| (has_moment(x, k) && has_moment(y, k)).then(|| raw_moment(x, k) * raw_moment(y, k));
+        let moments = moments_from_raw([raw(1), raw(2), raw(3)]);
+        let kernel = Kernel::new(x, y, false);
+        Product {
+            table: Table::new(|z| kernel.pdf(z), |z| kernel.cdf(z), support),
+            moments,
+        }
+    }
+
+    pub fn ratio(x: &dyn Cont, y: &dyn Cont) -> Product {
+        let support = interval_product((x.min(), x.max()), reciprocal((y.min(), y.max())));
+        let raw = |k| {
+            (has_moment(x, k) && has_inverse_moment(y, k))
+                .then(|| raw_moment(x, k) * raw_moment(y, -k))
+        };
+        let moments = moments_from_raw([raw(1), raw(2), raw(3)]);
+        let kernel = Kernel::new(y, x, true);
+        Product {
+            table: Table::new(|z| kernel.pdf(z), |z| kernel.cdf(z), support),
+            moments,
+        }
+    }
+}
+
+/// `X·Y` as an integral over `X`, or `X/Y` as one over `Y`: the outer
+/// variable is integrated over, at every value `t` of it the inner one takes
+/// the value `z/t` for the product and `z·t` for the ratio.
+struct Kernel<'a> {
+    outer: &'a dyn Cont,
+    inner: &'a dyn Cont,
+    ratio: bool,
+    // quantiles to split the integrals at
+    outer_points: Vec<f64>,
+    inner_points: Vec<f64>,
+}
+
+impl<'a> Kernel<'a> {
+    fn new(outer: &'a dyn Cont, inner: &'a dyn Cont, ratio: bool) -> Kernel<'a> {
+        let points = |d: &dyn Cont| -> Vec<f64> {
+            let mut points: Vec<f64> = QUANTILE_LEVELS.iter().map(|&q| d.inverse_cdf(q)).collect();
+            points.extend([d.min(), d.max(), 0.0]);
+            points.retain(|x| x.is_finite());
+            points
+        };
+        Kernel {
+            outer,
+            inner,
+            ratio,
+            outer_points: points(outer),
+            inner_points: points(inner),
+        }
+    }
+
+    fn inner_at(&self, z: f64, t: f64) -> f64 {
+        if self.ratio { z * t } else { z / t }
+    }
+
+    /// The outer quantiles and where the inner variable passes its own, as
+    /// far as they fall within the range of the outer one.
+    fn points(&self, z: f64) -> Vec<f64> {
+        let (lo, hi) = self
+            .outer_points
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &t| {
+                (lo.min(t), hi.max(t))
+            });
+        let mut points = self.outer_points.clone();
+        points.extend(
+            self.inner_points
+                .iter()
+                .map(|&q| if self.ratio { q / z } else { z / q })
+                .filter(|t| (lo..=hi).contains(t)),
+        );
+        points
+    }
+
+    fn pdf(&self, z: f64) -> f64 {
+        let integrand = |t: f64| {
+            if t == 0.0 {
+                return 0.0;
+            }
+            let jacobian = if self.ratio { t.abs() } else { 1.0 / t.abs() };
+            self.outer.pdf(t) * self.inner.pdf(self.inner_at(z, t)) * jacobian
+        };
+        integrate_pieces(integrand, self.points(z))
+    }
+
+    /// For a negative outer value the inequality flips, so the inner
+    /// variable contributes its survival function.
+    fn cdf(&self, z: f64) -> f64 {
+        let integrand = |t: f64| {
+            if t == 0.0 {
+                return 0.0;
+            }
+            let w = self.inner_at(z, t);
+            let p = if t > 0.0 {
+                self.inner.cdf(w)
+            } else {
+                self.inner.sf(w)
+            };
+            self.outer.pdf(t) * p
+        };
+        integrate_pieces(integrand, self.points(z)).clamp(0.0, 1.0)
+    }
+}
+
+/// Whether `E[|Y|^-k]` is finite. Away from zero it always is, otherwise
+/// the density must vanish faster than `|y|^(k-1)` there, which is read off
+/// the slope of the log-density close to zero.
+fn has_inverse_moment(d: &dyn Cont, k: i32) -> bool {
+    let (min, max) = (d.min(), d.max());
+    if min > 0.0 || max < 0.0 {
+        return true;
+    }
+    let eps = 1e-6 * (d.inverse_cdf(0.75) - d.inverse_cdf(0.25));
+    [eps, -eps].iter().all(|&e| {
+        let (near, far) = (d.pdf(e), d.pdf(2.0 * e));
+        if near <= 0.0 {
+            return true;
+        }
+        let power = (far / near).ln() / 2_f64.ln();
+        power > (k - 1) as f64 + 1e-4
+    })
+}
+
+/// Range of `a·b` for `a` and `b` in the given intervals, with `0·∞ = 0`.
+fn interval_product((a_lo, a_hi): (f64, f64), (b_lo, b_hi): (f64, f64)) -> (f64, f64) {
+    let mul = |a: f64, b: f64| if a == 0.0 || b == 0.0 { 0.0 } else { a * b };
+    let corners = [
+        mul(a_lo, b_lo),
+        mul(a_lo, b_hi),
+        mul(a_hi, b_lo),
+        mul(a_hi, b_hi),
+    ];
+    corners
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &c| {
+            (lo.min(c), hi.max(c))
+        })
+}
+
+/// Range of `1/b` for `b` in the interval, unbounded if it contains zero
+/// in its interior.
+fn reciprocal((lo, hi): (f64, f64)) -> (f64, f64) {
+    if lo < 0.0 && hi > 0.0 {
+        (f64::NEG_INFINITY, f64::INFINITY)
+    } else if lo == 0.0 {
+        (1.0 / hi, f64::INFINITY)
+    } else if hi == 0.0 {
+        (f64::NEG_INFINITY, 1.0 / lo)
+    } else {
+        (1.0 / hi, 1.0 / lo)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Product {
+    fn cdf(&self, z: f64) -> f64 {
+        self.table.cdf(z)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        self.table.inverse_cdf(p)
+    }
+}
+
+impl Min<f64> for Product {
+    fn min(&self) -> f64 {
+        self.table.min()
+    }
+}
+
+impl Max<f64> for Product {
+    fn max(&self) -> f64 {
+        self.table.max()
+    }
+}
+
+impl Distribution<f64> for Product {
+    fn mean(&self) -> Option<f64> {
+        self.moments.0
+    }
+
+    fn variance(&self) -> Option<f64> {
+        self.moments.1
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        Some(self.table.entropy())
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        self.moments.2
+    }
+}
+
+impl Continuous<f64, f64> for Product {
+    fn pdf(&self, z: f64) -> f64 {
+        self.table.pdf(z)
+    }
+
+    fn ln_pdf(&self, z: f64) -> f64 {
+        self.pdf(z).ln()
+    }
+}
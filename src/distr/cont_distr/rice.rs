@@ -0,0 +1,377 @@
+use crate::distr::numeric::{
+    EULER_MASCHERONI, bessel_i0e, bessel_i1e, invert_cdf, poisson_gamma_lr,
+};
+use statrs::{
+    distribution::{Continuous, ContinuousCDF},
+    statistics::{Distribution, Max, Min},
+};
+use std::f64::consts::PI;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum RiceError {
+    NuNegative,
+    SigmaNotPositive,
+}
+
+impl std::fmt::Display for RiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RiceError::NuNegative => write!(f, "Nu is NaN, infinite or less than zero"),
+            RiceError::SigmaNotPositive => {
+                write!(f, "Sigma is NaN, infinite, zero or less than zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RiceError {}
+
+/// Rayleigh distribution with scale `σ`, the amplitude of a 2D vector whose
+/// components are iid `N(0, σ²)`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Rayleigh {
+    sigma: f64,
+}
+
+impl Rayleigh {
+    pub fn new(sigma: f64) -> Result<Rayleigh, RiceError> {
+        if !sigma.is_finite() || sigma <= 0.0 {
+            return Err(RiceError::SigmaNotPositive);
+        }
+        Ok(Rayleigh { sigma })
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Rayleigh {
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        -(-x * x / (2.0 * self.sigma * self.sigma)).exp_m1()
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 1.0;
+        }
+        (-x * x / (2.0 * self.sigma * self.sigma)).exp()
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        self.sigma * (-2.0 * (-p).ln_1p()).sqrt()
+    }
+}
+
+impl Min<f64> for Rayleigh {
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for Rayleigh {
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Rayleigh {
+    fn mean(&self) -> Option<f64> {
+        Some(self.sigma * (PI / 2.0).sqrt())
+    }
+
+    fn variance(&self) -> Option<f64> {
+        Some((4.0 - PI) / 2.0 * self.sigma * self.sigma)
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        Some(1.0 + (self.sigma / 2_f64.sqrt()).ln() + EULER_MASCHERONI / 2.0)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        Some(2.0 * PI.sqrt() * (PI - 3.0) / (4.0 - PI).powf(1.5))
+    }
+}
+
+impl Continuous<f64, f64> for Rayleigh {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+        let s2 = self.sigma * self.sigma;
+        x / s2 * (-x * x / (2.0 * s2)).exp()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+/// Rice distribution with noncentrality `ν` and scale `σ`, the amplitude of a
+/// 2D vector at distance `ν` from the origin with iid `N(0, σ²)` noise.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Rice {
+    nu: f64,
+    sigma: f64,
+}
+
+impl Rice {
+    pub fn new(nu: f64, sigma: f64) -> Result<Rice, RiceError> {
+        if !nu.is_finite() || nu < 0.0 {
+            return Err(RiceError::NuNegative);
+        }
+        if !sigma.is_finite() || sigma <= 0.0 {
+            return Err(RiceError::SigmaNotPositive);
+        }
+        Ok(Rice { nu, sigma })
+    }
+
+    // half the noncentrality of the equivalent chi-squared, ν² / 2σ²
+    fn theta(&self) -> f64 {
+        self.nu * self.nu / (2.0 * self.sigma * self.sigma)
+    }
+
+    // Laguerre function L_{1/2}(-θ), scaled Bessel functions keep it finite
+    fn laguerre_half(&self) -> f64 {
+        let t = self.theta();
+        (1.0 + t) * bessel_i0e(t / 2.0) + t * bessel_i1e(t / 2.0)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Rice {
+    /// `(R / σ)²` is noncentral chi-squared with 2 degrees of freedom, whose
+    /// CDF is a Poisson weighted sum of central ones.
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        poisson_gamma_lr(self.theta(), 1.0, x * x / (2.0 * self.sigma * self.sigma))
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        let lo = (self.nu - 4.0 * self.sigma).max(0.0);
+        let hi = self.nu + 4.0 * self.sigma;
+        invert_cdf(|x| self.cdf(x), p, lo, hi)
+    }
+}
+
+impl Min<f64> for Rice {
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for Rice {
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Rice {
+    fn mean(&self) -> Option<f64> {
+        Some(self.sigma * (PI / 2.0).sqrt() * self.laguerre_half())
+    }
+
+    fn variance(&self) -> Option<f64> {
+        let mean = self.mean()?;
+        Some(2.0 * self.sigma * self.sigma + self.nu * self.nu - mean * mean)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        // E[R³] = 3 σ³ sqrt(π/2) L_{3/2}(-θ), L_{3/2} from the Laguerre recurrence
+        let t = self.theta();
+        let l32 = ((2.0 + t) * self.laguerre_half() - 0.5 * bessel_i0e(t / 2.0)) * 2.0 / 3.0;
+        let m3 = 3.0 * self.sigma.powi(3) * (PI / 2.0).sqrt() * l32;
+        let mean = self.mean()?;
+        let var = self.variance()?;
+        Some((m3 - 3.0 * mean * var - mean.powi(3)) / var.powf(1.5))
+    }
+}
+
+impl Continuous<f64, f64> for Rice {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+        let s2 = self.sigma * self.sigma;
+        let d = x - self.nu;
+        x / s2 * (-d * d / (2.0 * s2)).exp() * bessel_i0e(x * self.nu / s2)
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // reference values from mpmath at 30 digits
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() <= tolerance * expected.abs(),
+            "{actual} is not {expected}"
+        );
+    }
+
+    #[test]
+    fn bessel_small_arguments() {
+        assert_eq!(bessel_i0e(0.0), 1.0);
+        assert_eq!(bessel_i1e(0.0), 0.0);
+        assert_close(bessel_i0e(1e-3), 0.999_000_749_583_515_6, 1e-14);
+        assert_close(bessel_i1e(1e-3), 4.995_003_123_542_213e-4, 1e-14);
+        assert_close(bessel_i0e(1.0), 0.465_759_607_593_640_4, 1e-14);
+        assert_close(bessel_i1e(1.0), 0.207_910_415_349_708_45, 1e-14);
+        assert_close(bessel_i0e(5.0), 0.183_540_812_609_328_35, 1e-14);
+        assert_close(bessel_i1e(5.0), 0.163_972_266_944_542_36, 1e-14);
+    }
+
+    #[test]
+    fn bessel_around_the_switch_to_the_asymptotic_expansion() {
+        let cases = [
+            (29.9, 0.073_269_219_046_001_91, 0.072_033_374_911_868_79),
+            (30.0, 0.073_145_946_482_237_3, 0.071_916_330_598_647_55),
+            (30.1, 0.073_023_294_131_060_94, 0.071_799_854_351_014_34),
+        ];
+        for (x, i0e, i1e) in cases {
+            assert_close(bessel_i0e(x), i0e, 1e-13);
+            assert_close(bessel_i1e(x), i1e, 1e-13);
+        }
+    }
+
+    #[test]
+    fn bessel_large_arguments() {
+        let cases = [
+            (50.0, 0.056_561_626_647_454_19, 0.055_993_123_892_895_4),
+            (700.0, 0.015_081_295_651_531_358, 0.015_070_519_444_716_847),
+            (
+                1e5,
+                0.001_261_567_837_976_776_8,
+                0.001_261_561_530_121_817_1,
+            ),
+        ];
+        for (x, i0e, i1e) in cases {
+            assert_close(bessel_i0e(x), i0e, 1e-14);
+            assert_close(bessel_i1e(x), i1e, 1e-14);
+        }
+    }
+
+    #[test]
+    fn bessel_symmetry() {
+        assert_close(bessel_i0e(-2.0), 0.308_508_322_553_671_04, 1e-14);
+        assert_close(bessel_i1e(-2.0), -0.215_269_289_248_937_66, 1e-14);
+    }
+
+    #[test]
+    fn rayleigh() {
+        let d = Rayleigh::new(2.0).unwrap();
+        assert_close(d.pdf(1.0), 0.220_624_225_646_148_85, 1e-14);
+        assert_close(d.cdf(3.0), 0.675_347_532_641_650_3, 1e-14);
+        assert_close(d.sf(3.0), 1.0 - 0.675_347_532_641_650_3, 1e-14);
+        assert_close(d.inverse_cdf(0.9), 4.291_932_052_578_694, 1e-14);
+        assert_close(d.mean().unwrap(), 2.506_628_274_631_000_5, 1e-14);
+        assert_close(d.variance().unwrap(), 1.716_814_692_820_413_5, 1e-14);
+        assert_close(d.skewness().unwrap(), 0.631_110_657_818_937, 1e-14);
+        assert_close(d.entropy().unwrap(), 1.635_181_422_730_739, 1e-14);
+        assert_eq!(d.pdf(-1.0), 0.0);
+        assert_eq!(d.cdf(-1.0), 0.0);
+    }
+
+    #[test]
+    fn rice() {
+        // ν, σ, (x, pdf, cdf), the 0.3 quantile, mean, variance, skewness
+        type Case = (f64, f64, [(f64, f64, f64); 3], f64, f64, f64, f64);
+        let cases: [Case; 3] = [
+            (
+                1.0,
+                1.0,
+                [
+                    (0.5, 0.284_620_814_114_595_9, 0.073_472_602_043_352_03),
+                    (1.0, 0.465_759_607_593_640_4, 0.267_120_196_203_179_8),
+                    (4.0, 0.009_198_334_505_147_099, 0.997_110_467_229_352_4),
+                ],
+                1.069_594_457_748_722,
+                1.548_572_460_551_145_4,
+                0.601_923_334_422_571_3,
+                0.517_154_117_880_703,
+            ),
+            (
+                5.0,
+                2.0,
+                [
+                    (1.0, 0.013_866_322_839_430_777, 0.006_215_771_945_607_96),
+                    (5.0, 0.203_903_188_916_209_8, 0.418_438_724_433_516_1),
+                    (9.0, 0.036_642_964_226_798_33, 0.967_912_096_689_979_5),
+                ],
+                4.392_443_612_991_891,
+                5.422_402_937_527_482,
+                3.597_546_383_093_336,
+                0.112_929_000_202_332_3,
+            ),
+            (
+                30.0,
+                1.0,
+                [
+                    (28.0, 0.052_167_996_251_940_18, 0.021_834_628_135_073_506),
+                    (30.0, 0.398_997_723_722_044_55, 0.493_350_037_937_965_9),
+                    (33.0, 0.004_648_749_051_120_271, 0.998_577_988_281_067_4),
+                ],
+                29.492_411_936_564_33,
+                30.016_671_304_039_284,
+                0.999_443_825_264_560_4,
+                3.716_107_641_311_298_7e-5,
+            ),
+        ];
+        for (nu, sigma, points, quantile, mean, variance, skewness) in cases {
+            let d = Rice::new(nu, sigma).unwrap();
+            for (x, pdf, cdf) in points {
+                assert_close(d.pdf(x), pdf, 1e-12);
+                assert_close(d.cdf(x), cdf, 1e-12);
+            }
+            assert_close(d.inverse_cdf(0.3), quantile, 1e-12);
+            assert_close(d.mean().unwrap(), mean, 1e-13);
+            assert_close(d.variance().unwrap(), variance, 1e-10);
+            assert_close(d.skewness().unwrap(), skewness, 1e-4);
+        }
+    }
+
+    #[test]
+    fn rice_without_noncentrality_is_rayleigh() {
+        let (rice, rayleigh) = (Rice::new(0.0, 2.0).unwrap(), Rayleigh::new(2.0).unwrap());
+        for x in [0.5, 2.0, 5.0] {
+            assert_close(rice.pdf(x), rayleigh.pdf(x), 1e-14);
+            assert_close(rice.cdf(x), rayleigh.cdf(x), 1e-14);
+        }
+        assert_close(rice.mean().unwrap(), rayleigh.mean().unwrap(), 1e-14);
+        assert_close(
+            rice.variance().unwrap(),
+            rayleigh.variance().unwrap(),
+            1e-13,
+        );
+        assert_close(
+            rice.skewness().unwrap(),
+            rayleigh.skewness().unwrap(),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        assert_eq!(Rayleigh::new(0.0), Err(RiceError::SigmaNotPositive));
+        assert_eq!(Rice::new(-1.0, 1.0), Err(RiceError::NuNegative));
+        assert_eq!(Rice::new(1.0, f64::NAN), Err(RiceError::SigmaNotPositive));
+    }
+}
@@ -0,0 +1,677 @@
+use crate::distr::numeric::{integrate_pieces, invert_cdf, std_normal_cdf};
+use statrs::{
+    distribution::{Continuous, ContinuousCDF},
+    function::gamma::{gamma, ln_gamma},
+    statistics::{Distribution, Max, Min},
+};
+use std::{
+    cell::RefCell,
+    f64::consts::{FRAC_2_PI, FRAC_PI_2, PI},
+    rc::Rc,
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum StableError {
+    AlphaOutOfRange,
+    BetaOutOfRange,
+    LocationNotFinite,
+    ScaleNotPositive,
+}
+
+impl std::fmt::Display for StableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StableError::AlphaOutOfRange => write!(f, "Alpha is NaN or outside of (0, 2]"),
+            StableError::BetaOutOfRange => write!(f, "Beta is NaN or outside of [-1, 1]"),
+            StableError::LocationNotFinite => write!(f, "Location is NaN or infinite"),
+            StableError::ScaleNotPositive => {
+                write!(f, "Scale is NaN, infinite, zero or less than zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StableError {}
+
+/// Integrates `h(g(θ))` over `(lo, hi)` for a monotone `g` given by its
+/// logarithm. The integrands of Nolan's formulas are concentrated around
+/// `g = 1`, in a range of `θ` that narrows far out in the tails, so the range
+/// is split where `ln g` crosses each of a few levels around zero.
+fn integrate_nolan(ln_g: impl Fn(f64) -> f64, h: impl Fn(f64) -> f64, lo: f64, hi: f64) -> f64 {
+    const LEVELS: [f64; 8] = [-32.0, -8.0, -3.0, -1.0, 0.0, 1.0, 2.0, 4.0];
+    let f = |theta: f64| {
+        let v = h(ln_g(theta).exp());
+        if v.is_finite() { v } else { 0.0 }
+    };
+    let eps = 1e-12 * (hi - lo);
+    let (l, r) = (ln_g(lo + eps), ln_g(hi - eps));
+    let mut points = vec![lo, hi];
+    for level in LEVELS {
+        if (l - level) * (r - level) >= 0.0 || (l - level).is_nan() || (r - level).is_nan() {
+            continue;
+        }
+        let (mut a, mut b) = (lo, hi);
+        for _ in 0..100 {
+            let mid = 0.5 * (a + b);
+            if (ln_g(mid) - level).signum() == (l - level).signum() {
+                a = mid;
+            } else {
+                b = mid;
+            }
+        }
+        points.push(0.5 * (a + b));
+    }
+    integrate_pieces(f, points)
+}
+
+/// Stable distribution `S(α, β, γ, δ)` in Nolan's S1 parametrization, with
+/// stability `0 < α <= 2`, skewness `-1 <= β <= 1`, scale `γ` and location
+/// `δ`. `α = 2` is the normal with variance `2γ²`, `α = 1, β = 0` the Cauchy
+/// and `α = 1/2, β = 1` the Lévy distribution.
+///
+/// Only the normal and Cauchy cases have a closed form. Otherwise the density
+/// and CDF are computed from Nolan's (1997) integral representations, once
+/// per `(α, β)` into a table, and from their series in power-law tails.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Stable {
+    alpha: f64,
+    beta: f64,
+    scale: f64,
+    location: f64,
+}
+
+impl Stable {
+    pub fn new(alpha: f64, beta: f64, scale: f64, location: f64) -> Result<Stable, StableError> {
+        if !(alpha > 0.0 && alpha <= 2.0) {
+            return Err(StableError::AlphaOutOfRange);
+        }
+        if !(-1.0..=1.0).contains(&beta) {
+            return Err(StableError::BetaOutOfRange);
+        }
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(StableError::ScaleNotPositive);
+        }
+        if !location.is_finite() {
+            return Err(StableError::LocationNotFinite);
+        }
+        Ok(Stable {
+            alpha,
+            beta,
+            scale,
+            location,
+        })
+    }
+
+    /// Maps `x` onto the standard `S(α, β, 1, 0)` variable.
+    fn standardize(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if self.alpha == 1.0 {
+            z - FRAC_2_PI * self.beta * self.scale.ln()
+        } else {
+            z
+        }
+    }
+
+    fn standard_pdf(&self, z: f64) -> f64 {
+        let (alpha, beta) = (self.alpha, self.beta);
+        if alpha == 2.0 {
+            (-z * z / 4.0).exp() / (2.0 * PI.sqrt())
+        } else if alpha == 1.0 && beta == 0.0 {
+            1.0 / (PI * (1.0 + z * z))
+        } else {
+            StandardTable::get(alpha, beta)
+                .pdf(z)
+                .unwrap_or_else(|| exact_pdf(alpha, beta, z))
+        }
+    }
+
+    /// `P(Z <= z)` or, if `upper`, `P(Z > z)` of the standard variable.
+    fn standard_tail(&self, z: f64, upper: bool) -> f64 {
+        let (alpha, beta) = (self.alpha, self.beta);
+        let p = if alpha == 2.0 {
+            std_normal_cdf(if upper { -z } else { z } / 2_f64.sqrt())
+        } else if alpha == 1.0 && beta == 0.0 {
+            // rather than 1/2 ± arctan(z) / π, which cancels far out
+            1_f64.atan2(if upper { z } else { -z }) / PI
+        } else {
+            StandardTable::get(alpha, beta)
+                .tail(z, upper)
+                .unwrap_or_else(|| exact_tail(alpha, beta, z, upper))
+        };
+        p.clamp(0.0, 1.0)
+    }
+}
+
+/// Density of the standard S1 variable from its integral representation.
+fn exact_pdf(alpha: f64, beta: f64, z: f64) -> f64 {
+    if alpha == 1.0 {
+        unit_alpha_pdf(beta, z)
+    } else {
+        // the S0 variable is continuous in α, shift onto it
+        let zeta = -beta * (FRAC_PI_2 * alpha).tan();
+        nolan_pdf(alpha, beta, z + zeta)
+    }
+}
+
+/// Lower or upper tail of the standard S1 variable from its integral
+/// representation.
+fn exact_tail(alpha: f64, beta: f64, z: f64, upper: bool) -> f64 {
+    if alpha == 1.0 {
+        unit_alpha_tail(beta, z, upper)
+    } else {
+        let zeta = -beta * (FRAC_PI_2 * alpha).tan();
+        nolan_tail(alpha, beta, z + zeta, upper)
+    }
+}
+
+/// Density and upper tail of the standard S1 variable at `z > 0` from the
+/// series in powers of `z^-α` (Zolotarev 1986, §2.4), `None` if `z` is too
+/// close to the mode for it or the tail is not a power law.
+///
+/// With `θ₀ = arctan(β tan(πα/2)) / α` and `y = z cos(αθ₀)^(1/α)`,
+/// `P(Z > z) = Σ (-1)^(k+1) Γ(kα) sin(kα(π/2 + θ₀)) y^(-kα) / (π k!)`. The
+/// series converges for `α < 1`, where it is used unless its terms cancel,
+/// and is asymptotic for `α > 1`, where it is only used once its terms have
+/// dropped below rounding without growing again.
+fn tail_series(alpha: f64, beta: f64, z: f64) -> Option<(f64, f64)> {
+    let theta0 = (beta * (FRAC_PI_2 * alpha).tan()).atan() / alpha;
+    let angle = alpha * (FRAC_PI_2 + theta0);
+    if alpha == 1.0 || angle.sin() < 1e-9 {
+        return None;
+    }
+    let scale = (alpha * theta0).cos().powf(-1.0 / alpha);
+    let ln_y = (z / scale).ln();
+    let (mut pdf, mut tail, mut largest) = (0.0, 0.0, 0.0_f64);
+    let mut last = f64::INFINITY;
+    for k in 1..=MAX_SERIES_TERMS {
+        let ka = k as f64 * alpha;
+        let size = (ln_gamma(ka) - ln_gamma(k as f64 + 1.0) - ka * ln_y).exp() / PI;
+        if size > last && alpha > 1.0 {
+            return None;
+        }
+        let term = if k % 2 == 1 { size } else { -size } * (k as f64 * angle).sin();
+        tail += term;
+        pdf += term * ka;
+        largest = largest.max(term.abs());
+        last = size;
+        if size < f64::EPSILON * tail.abs() * 0.1 {
+            // terms far above the sum would have cancelled to noise
+            let precise = largest < 1e2 * tail.abs();
+            return (precise && tail > 0.0 && pdf > 0.0).then(|| (pdf / (z / scale) / scale, tail));
+        }
+    }
+    None
+}
+
+const MAX_SERIES_TERMS: usize = 40;
+
+/// Logarithms of the standard density and tails at nodes evenly spaced in
+/// `asinh` of the S0 variable, which keeps its mode near zero for `α` close
+/// to 1, or in the logarithm of the distance to the bound of a totally
+/// skewed distribution, between the points where `tail_series` takes over or
+/// the tail becomes negligible. The logarithms are smooth there, the rare points
+/// beyond a light tail are integrated.
+///
+/// Every evaluation of the integral representations is an adaptive
+/// quadrature, too slow for the thousands of them that plots and composed
+/// distributions make, so each parameter set is tabulated once and kept.
+struct StandardTable {
+    alpha: f64,
+    beta: f64,
+    /// Where the series takes over, or infinite on a light side.
+    series: (f64, f64),
+    /// `ζ`, added to `z` for the S0 variable.
+    shift: f64,
+    /// `1` or `-1` for a bound at zero below or above, otherwise `0`.
+    bound: f64,
+    start: f64,
+    step: f64,
+    ln_pdf: Vec<f64>,
+    ln_lower: Vec<f64>,
+    ln_upper: Vec<f64>,
+}
+
+/// Tables keyed on the bits of `(α, β)`.
+type Tables = Vec<((u64, u64), Rc<StandardTable>)>;
+
+thread_local! {
+    /// Tables of the parameter sets used last, the most recent at the end.
+    static TABLES: RefCell<Tables> = const { RefCell::new(Vec::new()) };
+}
+
+impl StandardTable {
+    const NODES: usize = 257;
+    const KEPT: usize = 8;
+    /// Mass beyond the end of a light tail.
+    const TAIL: f64 = 1e-17;
+    /// End of a tail that is neither light nor covered by the series, in
+    /// units of the S0 variable.
+    const REACH: f64 = 1e4;
+    /// Nearest and furthest the series is tried, it is cheap and converges
+    /// for all `α != 1` eventually.
+    const SERIES_START: f64 = 1.0 / 1024.0;
+    const SERIES_REACH: f64 = 1e12;
+
+    fn get(alpha: f64, beta: f64) -> Rc<StandardTable> {
+        let key = (alpha.to_bits(), beta.to_bits());
+        TABLES.with_borrow_mut(|tables| {
+            if let Some(i) = tables.iter().position(|(k, _)| *k == key) {
+                let entry = tables.remove(i);
+                let table = entry.1.clone();
+                tables.push(entry);
+                return table;
+            }
+            let table = Rc::new(StandardTable::new(alpha, beta));
+            if tables.len() == Self::KEPT {
+                tables.remove(0);
+            }
+            tables.push((key, table.clone()));
+            table
+        })
+    }
+
+    fn new(alpha: f64, beta: f64) -> StandardTable {
+        let shift = if alpha == 1.0 {
+            0.0
+        } else {
+            -beta * (FRAC_PI_2 * alpha).tan()
+        };
+        // the series on a heavy side, otherwise steps out until the tail is
+        // negligible or, on a bounded side, in towards the bound, all in `z`
+        let end = |upper: bool| {
+            let (sign, skew) = if upper { (1.0, beta) } else { (-1.0, -beta) };
+            if alpha < 1.0 && skew == -1.0 {
+                let mut z = -sign;
+                while exact_tail(alpha, beta, z, upper) > Self::TAIL {
+                    z /= 2.0;
+                }
+                return (z, f64::INFINITY);
+            }
+            let mut w = Self::SERIES_START;
+            while w < Self::SERIES_REACH {
+                let z = sign * w - shift;
+                if sign * z > 0.0 && tail_series(alpha, skew, sign * z).is_some() {
+                    return (z, sign * z);
+                }
+                w *= 2.0;
+            }
+            let mut w = 1.0;
+            while w < Self::REACH && exact_tail(alpha, beta, sign * w - shift, upper) > Self::TAIL {
+                w *= 2.0;
+            }
+            (sign * w - shift, f64::INFINITY)
+        };
+        let ((lo, lo_series), (hi, hi_series)) = (end(false), end(true));
+        let bound = if alpha < 1.0 && beta.abs() == 1.0 {
+            beta
+        } else {
+            0.0
+        };
+        let mut table = StandardTable {
+            alpha,
+            beta,
+            series: (-lo_series, hi_series),
+            shift,
+            bound,
+            start: 0.0,
+            step: 0.0,
+            ln_pdf: Vec::with_capacity(Self::NODES),
+            ln_lower: Vec::with_capacity(Self::NODES),
+            ln_upper: Vec::with_capacity(Self::NODES),
+        };
+        let (start, stop) = (table.node_at(lo), table.node_at(hi));
+        let (start, stop) = (start.min(stop), start.max(stop));
+        table.start = start;
+        table.step = (stop - start) / (Self::NODES - 1) as f64;
+        for i in 0..Self::NODES {
+            let z = table.node_to_z(start + i as f64 * table.step);
+            table.ln_pdf.push(exact_pdf(alpha, beta, z).ln());
+            // the smaller tail is computed, the other one follows from it
+            let upper = z > 0.0;
+            let tail = exact_tail(alpha, beta, z, upper).clamp(0.0, 1.0);
+            let (small, large) = (tail.ln(), (-tail).ln_1p());
+            let (lower, upper) = if upper {
+                (large, small)
+            } else {
+                (small, large)
+            };
+            table.ln_lower.push(lower);
+            table.ln_upper.push(upper);
+        }
+        table
+    }
+
+    /// Coordinate of `z` along the nodes.
+    fn node_at(&self, z: f64) -> f64 {
+        if self.bound == 0.0 {
+            (z + self.shift).asinh()
+        } else {
+            (self.bound * z).ln()
+        }
+    }
+
+    fn node_to_z(&self, u: f64) -> f64 {
+        if self.bound == 0.0 {
+            u.sinh() - self.shift
+        } else {
+            self.bound * u.exp()
+        }
+    }
+
+    /// Density and lower and upper tail at `z` from the series, if beyond
+    /// where it takes over.
+    fn series(&self, z: f64) -> Option<(f64, f64, f64)> {
+        if z >= self.series.1 {
+            let (pdf, upper) = tail_series(self.alpha, self.beta, z)?;
+            Some((pdf, 1.0 - upper, upper))
+        } else if z <= self.series.0 {
+            let (pdf, lower) = tail_series(self.alpha, -self.beta, -z)?;
+            Some((pdf, lower, 1.0 - lower))
+        } else {
+            None
+        }
+    }
+
+    fn pdf(&self, z: f64) -> Option<f64> {
+        match self.series(z) {
+            Some((pdf, _, _)) => Some(pdf),
+            None => self.interpolate(&self.ln_pdf, z).map(f64::exp),
+        }
+    }
+
+    fn tail(&self, z: f64, upper: bool) -> Option<f64> {
+        match self.series(z) {
+            Some((_, lower, above)) => Some(if upper { above } else { lower }),
+            None => {
+                let ln_tail = if upper {
+                    &self.ln_upper
+                } else {
+                    &self.ln_lower
+                };
+                self.interpolate(ln_tail, z).map(f64::exp)
+            }
+        }
+    }
+
+    /// Cubic interpolation of `values` at `z`. `None` beyond the outermost
+    /// nodes or next to one where the logarithm is not finite.
+    fn interpolate(&self, values: &[f64], z: f64) -> Option<f64> {
+        let last = values.len() - 1;
+        let t = (self.node_at(z) - self.start) / self.step;
+        if !(0.0..=last as f64).contains(&t) {
+            return None;
+        }
+        let i = (t.floor() as usize).clamp(1, last - 2);
+        let s = t - i as f64;
+        let w = [
+            -s * (s - 1.0) * (s - 2.0) / 6.0,
+            (s + 1.0) * (s - 1.0) * (s - 2.0) / 2.0,
+            -(s + 1.0) * s * (s - 2.0) / 2.0,
+            (s + 1.0) * s * (s - 1.0) / 6.0,
+        ];
+        let v: f64 = w.iter().zip(&values[i - 1..]).map(|(w, v)| w * v).sum();
+        v.is_finite().then_some(v)
+    }
+}
+
+/// Density of the standard S0 variable for `α != 1`.
+fn nolan_pdf(alpha: f64, beta: f64, x: f64) -> f64 {
+    let zeta = -beta * (FRAC_PI_2 * alpha).tan();
+    if x < zeta {
+        return nolan_pdf(alpha, -beta, -x);
+    }
+    let theta0 = (beta * (FRAC_PI_2 * alpha).tan()).atan() / alpha;
+    if x - zeta < 1e-10 * zeta.abs().max(1.0) {
+        return gamma(1.0 + 1.0 / alpha) * theta0.cos()
+            / (PI * (1.0 + zeta * zeta).powf(1.0 / (2.0 * alpha)));
+    }
+    let ln_g = nolan_ln_g(alpha, theta0, x - zeta);
+    let integral = integrate_nolan(ln_g, |g| g * (-g).exp(), -theta0, FRAC_PI_2);
+    alpha / (PI * (alpha - 1.0).abs() * (x - zeta)) * integral
+}
+
+/// Lower or upper tail of the standard S0 variable for `α != 1`.
+fn nolan_tail(alpha: f64, beta: f64, x: f64, upper: bool) -> f64 {
+    let zeta = -beta * (FRAC_PI_2 * alpha).tan();
+    if x < zeta {
+        return nolan_tail(alpha, -beta, -x, !upper);
+    }
+    let theta0 = (beta * (FRAC_PI_2 * alpha).tan()).atan() / alpha;
+    // P(Z <= ζ) for α < 1
+    let at_zeta = (FRAC_PI_2 - theta0) / PI;
+    if x - zeta < 1e-10 * zeta.abs().max(1.0) {
+        return if upper { 1.0 - at_zeta } else { at_zeta };
+    }
+    let ln_g = nolan_ln_g(alpha, theta0, x - zeta);
+    let integral = |h: fn(f64) -> f64| integrate_nolan(&ln_g, h, -theta0, FRAC_PI_2) / PI;
+    match (alpha > 1.0, upper) {
+        (true, true) => integral(|g| (-g).exp()),
+        (true, false) => 1.0 - integral(|g| (-g).exp()),
+        (false, true) => integral(|g| -(-g).exp_m1()),
+        (false, false) => at_zeta + integral(|g| (-g).exp()),
+    }
+}
+
+/// `ln g(θ) = α/(α-1) ln(x - ζ) + ln V(θ)` of Nolan's representation.
+fn nolan_ln_g(alpha: f64, theta0: f64, offset: f64) -> impl Fn(f64) -> f64 {
+    let a1 = alpha - 1.0;
+    let ln_c = alpha / a1 * offset.ln() + (alpha * theta0).cos().ln() / a1;
+    move |theta: f64| {
+        let cos = theta.cos();
+        ln_c + alpha / a1 * (cos.ln() - (alpha * (theta0 + theta)).sin().ln())
+            + (alpha * theta0 + a1 * theta).cos().ln()
+            - cos.ln()
+    }
+}
+
+/// `ln g(θ)` of Nolan's representation for `α = 1`, `β > 0`.
+fn unit_alpha_ln_g(beta: f64, z: f64) -> impl Fn(f64) -> f64 {
+    let ln_c = -FRAC_PI_2 * z / beta;
+    move |theta: f64| {
+        let b = FRAC_PI_2 + beta * theta;
+        ln_c + FRAC_2_PI.ln() + (b / theta.cos()).ln() + b * theta.tan() / beta
+    }
+}
+
+fn unit_alpha_pdf(beta: f64, z: f64) -> f64 {
+    if beta < 0.0 {
+        return unit_alpha_pdf(-beta, -z);
+    }
+    let ln_g = unit_alpha_ln_g(beta, z);
+    integrate_nolan(ln_g, |g| g * (-g).exp(), -FRAC_PI_2, FRAC_PI_2) / (2.0 * beta)
+}
+
+fn unit_alpha_tail(beta: f64, z: f64, upper: bool) -> f64 {
+    if beta < 0.0 {
+        return unit_alpha_tail(-beta, -z, !upper);
+    }
+    let ln_g = unit_alpha_ln_g(beta, z);
+    let h: fn(f64) -> f64 = if upper {
+        |g| -(-g).exp_m1()
+    } else {
+        |g| (-g).exp()
+    };
+    integrate_nolan(ln_g, h, -FRAC_PI_2, FRAC_PI_2) / PI
+}
+
+impl ContinuousCDF<f64, f64> for Stable {
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.min() {
+            return 0.0;
+        }
+        if x >= self.max() {
+            return 1.0;
+        }
+        self.standard_tail(self.standardize(x), false)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.min() {
+            return 1.0;
+        }
+        if x >= self.max() {
+            return 0.0;
+        }
+        self.standard_tail(self.standardize(x), true)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        let (lo, hi) = (
+            self.location - 4.0 * self.scale,
+            self.location + 4.0 * self.scale,
+        );
+        invert_cdf(|x| self.cdf(x), p, lo.max(self.min()), hi.min(self.max()))
+    }
+}
+
+impl Min<f64> for Stable {
+    /// Totally skewed stable distributions with `α < 1` are bounded on one side.
+    fn min(&self) -> f64 {
+        if self.alpha < 1.0 && self.beta == 1.0 {
+            self.location
+        } else {
+            f64::NEG_INFINITY
+        }
+    }
+}
+
+impl Max<f64> for Stable {
+    fn max(&self) -> f64 {
+        if self.alpha < 1.0 && self.beta == -1.0 {
+            self.location
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl Distribution<f64> for Stable {
+    fn mean(&self) -> Option<f64> {
+        if self.alpha > 1.0 {
+            Some(self.location)
+        } else {
+            None
+        }
+    }
+
+    fn variance(&self) -> Option<f64> {
+        if self.alpha == 2.0 {
+            Some(2.0 * self.scale * self.scale)
+        } else {
+            None
+        }
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        if self.alpha == 2.0 { Some(0.0) } else { None }
+    }
+}
+
+impl Continuous<f64, f64> for Stable {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.min() || x > self.max() {
+            return 0.0;
+        }
+        self.standard_pdf(self.standardize(x)) / self.scale
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distr::cont_distr::Levy;
+    use statrs::distribution::{Cauchy, Normal};
+
+    fn assert_close(actual: f64, expected: f64, relative: f64) {
+        assert!(
+            (actual - expected).abs() <= relative * expected.abs(),
+            "{actual} is not {expected}"
+        );
+    }
+
+    #[test]
+    fn alpha_two_is_normal() {
+        // β has no effect at α = 2
+        let stable = Stable::new(2.0, 0.3, 1.5, -1.0).unwrap();
+        let normal = Normal::new(-1.0, 2_f64.sqrt() * 1.5).unwrap();
+        for x in [-20.0, -8.0, -1.0, 0.5, 4.0, 15.0] {
+            assert_close(stable.pdf(x), normal.pdf(x), 1e-9);
+            assert_close(stable.cdf(x), normal.cdf(x), 1e-9);
+            assert_close(stable.sf(x), normal.sf(x), 1e-9);
+        }
+        for p in [1e-12, 0.01, 0.5, 0.99] {
+            assert!((stable.inverse_cdf(p) - normal.inverse_cdf(p)).abs() < 1e-9);
+        }
+        assert_close(stable.variance().unwrap(), 4.5, 1e-15);
+    }
+
+    #[test]
+    fn alpha_one_symmetric_is_cauchy() {
+        let stable = Stable::new(1.0, 0.0, 0.5, 2.0).unwrap();
+        let cauchy = Cauchy::new(2.0, 0.5).unwrap();
+        for x in [-3.0, 2.0, 2.7, 100.0] {
+            assert_close(stable.pdf(x), cauchy.pdf(x), 1e-12);
+            assert_close(stable.cdf(x), cauchy.cdf(x), 1e-12);
+            assert_close(stable.sf(x), cauchy.sf(x), 1e-12);
+        }
+        // far tails against arctan(γ / |x - δ|) / π, statrs cancels there
+        assert_close(stable.cdf(-1e6), (0.5_f64 / (1e6 + 2.0)).atan() / PI, 1e-12);
+        assert_close(stable.sf(1e8), (0.5_f64 / (1e8 - 2.0)).atan() / PI, 1e-12);
+        assert_close(stable.pdf(1e8), cauchy.pdf(1e8), 1e-12);
+        for p in [1e-9, 0.01, 0.5, 0.99] {
+            // δ - γ cot(πp), which keeps its precision for small p
+            assert_close(stable.inverse_cdf(p), 2.0 - 0.5 / (PI * p).tan(), 1e-9);
+        }
+        assert_eq!(stable.mean(), None);
+    }
+
+    #[test]
+    fn alpha_half_totally_skewed_is_levy() {
+        let stable = Stable::new(0.5, 1.0, 2.0, 1.0).unwrap();
+        let levy = Levy::new(1.0, 2.0).unwrap();
+        assert_eq!(stable.min(), 1.0);
+        assert_eq!(stable.pdf(0.5), 0.0);
+        // from the light tail next to the bound through the table to the
+        // power-law tail of the series
+        for x in [1.05, 1.1, 1.3, 2.0, 3.0, 10.0, 100.0, 1e4, 1e9] {
+            assert_close(stable.pdf(x), levy.pdf(x), 1e-6);
+            assert_close(stable.cdf(x), levy.cdf(x), 1e-6);
+            assert_close(stable.sf(x), levy.sf(x), 1e-6);
+        }
+        for p in [1e-12, 1e-6, 0.01, 0.3, 0.9, 0.999, 1.0 - 1e-9] {
+            assert_close(stable.inverse_cdf(p), levy.inverse_cdf(p), 1e-6);
+        }
+    }
+
+    #[test]
+    fn beta_minus_one_mirrors_levy() {
+        let stable = Stable::new(0.5, -1.0, 2.0, 1.0).unwrap();
+        let levy = Levy::new(1.0, 2.0).unwrap();
+        assert_eq!(stable.max(), 1.0);
+        for x in [0.9, 0.5, -1.0, -100.0] {
+            assert_close(stable.pdf(x), levy.pdf(2.0 - x), 1e-6);
+            assert_close(stable.cdf(x), levy.sf(2.0 - x), 1e-6);
+        }
+    }
+
+    #[test]
+    fn integrals_match_levy() {
+        // the integral representations on their own, without the table
+        let levy = Levy::new(0.0, 1.0).unwrap();
+        for z in [0.05, 0.3, 1.0, 4.0, 50.0] {
+            assert_close(exact_pdf(0.5, 1.0, z), levy.pdf(z), 1e-8);
+            assert_close(exact_tail(0.5, 1.0, z, false), levy.cdf(z), 1e-8);
+            assert_close(exact_tail(0.5, 1.0, z, true), levy.sf(z), 1e-8);
+        }
+    }
+}
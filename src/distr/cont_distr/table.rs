@@ -0,0 +1,131 @@
+use crate::distr::numeric::{central_range, integrate_pieces, invert_cdf};
+
+/// Number of points the density and CDF are tabulated at, before the
+/// refinements around zero and the ends of the range.
+const NODES: usize = 512;
+
+/// Density and CDF of a distribution that is expensive to evaluate,
+/// tabulated at nodes that are dense around the median and sparse in the
+/// tails and interpolated linearly in between. Nodes are also packed towards
+/// zero and the ends of the range, where the density may be singular. Tails
+/// beyond a thousand interquartile ranges are left out.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct Table {
+    nodes: Vec<f64>,
+    pdfs: Vec<f64>,
+    cdfs: Vec<f64>,
+    min: f64,
+    max: f64,
+}
+
+impl Table {
+    pub(crate) fn new(
+        pdf: impl Fn(f64) -> f64,
+        cdf: impl Fn(f64) -> f64,
+        (min, max): (f64, f64),
+    ) -> Table {
+        let quantile = |p: f64| invert_cdf(&cdf, p, -1.0, 1.0).clamp(min, max);
+        let (lo, hi) = central_range(quantile);
+        let (median, iqr) = (quantile(0.5), quantile(0.75) - quantile(0.25));
+        // z = median + scale·sinh(u) for equidistant u
+        let scale = if iqr > 0.0 { 0.5 * iqr } else { 1.0 };
+        let (u_lo, u_hi) = (
+            ((lo - median) / scale).asinh(),
+            ((hi - median) / scale).asinh(),
+        );
+        let du = (u_hi - u_lo) / (NODES - 1) as f64;
+        let mut nodes: Vec<f64> = (0..NODES)
+            .map(|i| (median + scale * (u_lo + du * i as f64).sinh()).clamp(lo, hi))
+            .collect();
+        let mut anchors = vec![lo, hi];
+        if lo < 0.0 && hi > 0.0 {
+            anchors.push(0.0);
+        }
+        for a in anchors {
+            // a few node spacings, halved down to nothing
+            let spacing = 4.0 * scale * du * ((a - median) / scale).asinh().cosh();
+            nodes.extend(
+                (1..=40)
+                    .flat_map(|j| [a - spacing / 2_f64.powi(j), a + spacing / 2_f64.powi(j)])
+                    .filter(|z| (lo..=hi).contains(z)),
+            );
+        }
+        nodes.sort_by(f64::total_cmp);
+        nodes.dedup();
+        Table {
+            pdfs: nodes.iter().map(|&z| pdf(z)).collect(),
+            cdfs: nodes.iter().map(|&z| cdf(z)).collect(),
+            nodes,
+            min,
+            max,
+        }
+    }
+
+    /// Index of the last node at or below `z` and the fraction of the way to
+    /// the next one, `None` outside of the tabulated range.
+    fn locate(&self, z: f64) -> Option<(usize, f64)> {
+        let last = self.nodes.len() - 1;
+        if !(z >= self.nodes[0] && z <= self.nodes[last]) {
+            return None;
+        }
+        let i = self.nodes.partition_point(|&n| n <= z).clamp(1, last) - 1;
+        let width = self.nodes[i + 1] - self.nodes[i];
+        let t = if width > 0.0 {
+            (z - self.nodes[i]) / width
+        } else {
+            0.0
+        };
+        Some((i, t))
+    }
+
+    pub(crate) fn pdf(&self, z: f64) -> f64 {
+        match self.locate(z) {
+            Some((i, t)) => self.pdfs[i] + t * (self.pdfs[i + 1] - self.pdfs[i]),
+            None => 0.0,
+        }
+    }
+
+    pub(crate) fn cdf(&self, z: f64) -> f64 {
+        match self.locate(z) {
+            Some((i, t)) => self.cdfs[i] + t * (self.cdfs[i + 1] - self.cdfs[i]),
+            None if z < self.nodes[0] => 0.0,
+            None => 1.0,
+        }
+    }
+
+    pub(crate) fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min;
+        }
+        if p >= 1.0 {
+            return self.max;
+        }
+        let last = self.cdfs.len() - 1;
+        let i = self.cdfs.partition_point(|&c| c < p).clamp(1, last) - 1;
+        let rise = self.cdfs[i + 1] - self.cdfs[i];
+        let t = if rise > 0.0 {
+            (p - self.cdfs[i]) / rise
+        } else {
+            0.5
+        };
+        // in the left out tails the range ends are the best guess
+        self.nodes[i] + t.clamp(0.0, 1.0) * (self.nodes[i + 1] - self.nodes[i])
+    }
+
+    pub(crate) fn min(&self) -> f64 {
+        self.min
+    }
+
+    pub(crate) fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Entropy of the interpolated density.
+    pub(crate) fn entropy(&self) -> f64 {
+        let integrand = |z: f64| {
+            let p = self.pdf(z);
+            if p > 0.0 { -p * p.ln() } else { 0.0 }
+        };
+        integrate_pieces(integrand, self.nodes.clone())
+    }
+}
@@ -0,0 +1,718 @@
+use super::{Cont, Table};
+use crate::distr::{
+    expr::Expr,
+    numeric::{
+        QUANTILE_LEVELS, converged_integral, integrate_pieces, invert_cdf, moments_from_raw,
+        std_normal_cdf,
+    },
+};
+use statrs::{
+    distribution::{Continuous, ContinuousCDF},
+    statistics::{Distribution, Max, Min},
+};
+use std::{f64::consts::PI, rc::Rc};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum TransformError {
+    Undefined,
+    Constant,
+}
+
+impl std::fmt::Display for TransformError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransformError::Undefined => write!(f, "g(x) is not defined for every value X takes"),
+            TransformError::Constant => write!(f, "g(X) takes a single value"),
+        }
+    }
+}
+
+impl std::error::Error for TransformError {}
+
+/// Number of draws of `X` that `g` is split into monotone branches by, and
+/// its density estimated from where that fails.
+const DRAWS: usize = 5_000;
+
+/// Most branches `g` is split into before its density is estimated instead.
+const MAX_BRANCHES: usize = 64;
+
+/// Distribution of `Y = g(X)` for a function `g` given as an expression.
+///
+/// The range of `X` is split into branches over which `g` is monotone, at
+/// the turning points of `g` and at its poles. The density follows from the
+/// change of variables, the sum of `f(x) / |g'(x)|` over the `x` with
+/// `g(x) = y` on each branch, found by Newton's method. Where `g` turns too
+/// often for that, it is a kernel density estimate from draws of `X`,
+/// tabulated. The draws are taken at evenly spaced quantile levels rather
+/// than at random, so the estimate does not change from one frame to the
+/// next. Moments are `E[g(X)^k]`, integrated over `X`.
+#[derive(Clone)]
+pub struct Transformed {
+    distr: Rc<dyn Cont>,
+    g: Expr,
+    shape: Shape,
+    // quartiles of X, to start the search for g⁻¹(y) from, and of the draws
+    // of g(X), to start the search for quantiles from
+    quartiles: (f64, f64),
+    spread: (f64, f64),
+    min: f64,
+    max: f64,
+    moments: (Option<f64>, Option<f64>, Option<f64>),
+    entropy: f64,
+}
+
+#[derive(Clone)]
+enum Shape {
+    Branches(Vec<Branch>),
+    Estimated(Table),
+}
+
+/// Stretch `[lo, hi]` of the range of `X` over which `g` is monotone, with
+/// the values `g` approaches at either end.
+#[derive(Copy, Clone, Debug)]
+struct Branch {
+    lo: f64,
+    hi: f64,
+    g_lo: f64,
+    g_hi: f64,
+    rising: bool,
+}
+
+impl Branch {
+    /// Smallest and largest value of `g` on the branch.
+    fn range(&self) -> (f64, f64) {
+        (self.g_lo.min(self.g_hi), self.g_lo.max(self.g_hi))
+    }
+}
+
+impl Transformed {
+    pub fn new(distr: Box<dyn Cont>, g: Expr) -> Result<Transformed, TransformError> {
+        let distr: Rc<dyn Cont> = Rc::from(distr);
+        let draws: Vec<f64> = (0..DRAWS)
+            .map(|i| distr.inverse_cdf((i as f64 + 0.5) / DRAWS as f64))
+            .collect();
+        let tails: Vec<f64> = QUANTILE_LEVELS
+            .iter()
+            .map(|&q| distr.inverse_cdf(q))
+            .collect();
+        // the far tails only take part in finding the branches
+        let mut xs: Vec<f64> = draws.iter().chain(tails.iter()).copied().collect();
+        xs.sort_by(f64::total_cmp);
+        xs.dedup();
+        let ys: Vec<f64> = xs.iter().map(|&x| g.eval(x)).collect();
+        if ys.iter().any(|y| y.is_nan()) {
+            return Err(TransformError::Undefined);
+        }
+        if ys.windows(2).all(|w| w[0] == w[1]) {
+            return Err(TransformError::Constant);
+        }
+
+        let mut values: Vec<f64> = draws.iter().map(|&x| g.eval(x)).collect();
+        let (shape, min, max) = match branches(&g, (distr.min(), distr.max()), &xs, &ys) {
+            Some(branches) => {
+                let ranges = branches.iter().map(Branch::range);
+                let min = ranges.clone().map(|r| r.0).fold(f64::INFINITY, f64::min);
+                let max = ranges.map(|r| r.1).fold(f64::NEG_INFINITY, f64::max);
+                (Shape::Branches(branches), min, max)
+            }
+            None => {
+                if values.iter().any(|y| !y.is_finite()) {
+                    return Err(TransformError::Undefined);
+                }
+                let kde = Kde::new(values.clone()).ok_or(TransformError::Constant)?;
+                let table = Table::new(|y| kde.pdf(y), |y| kde.cdf(y), (kde.lo, kde.hi));
+                let (min, max) = (table.min(), table.max());
+                (Shape::Estimated(table), min, max)
+            }
+        };
+        let peaks = match &shape {
+            Shape::Branches(branches) if branches.len() == 1 => Vec::new(),
+            _ => peaks(&draws, &values),
+        };
+        let moments = moments(distr.as_ref(), &g, &tails, &peaks);
+        values.sort_by(f64::total_cmp);
+        let mut transformed = Transformed {
+            quartiles: (distr.inverse_cdf(0.25), distr.inverse_cdf(0.75)),
+            spread: (values[DRAWS / 4], values[3 * DRAWS / 4]),
+            distr,
+            g,
+            shape,
+            min,
+            max,
+            moments,
+            entropy: 0.0,
+        };
+        transformed.entropy = match &transformed.shape {
+            Shape::Estimated(table) => table.entropy(),
+            Shape::Branches(branches) => transformed.branch_entropy(&tails, branches),
+        };
+        Ok(transformed)
+    }
+
+    /// Differential entropy as `-E[ln f_Y(g(X))]`, integrated over `X` rather
+    /// than `Y`, since the density of `Y` may be singular at the values `g`
+    /// turns at and that of `X` is not. Split wherever `g(x)` is one of
+    /// them, as the density of `Y` jumps there.
+    fn branch_entropy(&self, tails: &[f64], branches: &[Branch]) -> f64 {
+        let mut points = tails.to_vec();
+        for b in branches {
+            points.extend([b.lo, b.hi]);
+            for end in branches.iter().flat_map(|e| [e.g_lo, e.g_hi]) {
+                let (bottom, top) = b.range();
+                if end > bottom && end < top {
+                    points.push(self.preimage(b, end));
+                }
+            }
+        }
+        let integrand = |x: f64| {
+            let (p, q) = (self.distr.pdf(x), self.pdf(self.g.eval(x)));
+            if p > 0.0 && q > 0.0 { -p * q.ln() } else { 0.0 }
+        };
+        integrate_pieces(integrand, points)
+    }
+
+    /// The `x` on `branch` with `g(x) = y`, for `y` within its range, by
+    /// Newton's method within a bracket it falls back to bisecting. The
+    /// bracket is widened from the quartiles of `X` as far as it takes.
+    fn preimage(&self, branch: &Branch, y: f64) -> f64 {
+        let (lo, hi) = (branch.lo, branch.hi);
+        let sign = if branch.rising { 1.0 } else { -1.0 };
+        // rises through zero at the preimage
+        let f = |x: f64| {
+            let (v, slope) = self.g.eval_with_derivative(x);
+            (sign * (v - y), sign * slope)
+        };
+        let (mut a, mut b) = (
+            self.quartiles.0.clamp(lo, hi),
+            self.quartiles.1.clamp(lo, hi),
+        );
+        let mut step = (b - a).max(1.0);
+        while a > lo && f(a).0 > 0.0 {
+            a = (a - step).max(lo);
+            step *= 2.0;
+        }
+        let mut step = (b - a).max(1.0);
+        while b < hi && f(b).0 < 0.0 {
+            b = (b + step).min(hi);
+            step *= 2.0;
+        }
+        if !a.is_finite() || !b.is_finite() {
+            return if a.is_finite() { b } else { a };
+        }
+        let mut x = 0.5 * (a + b);
+        for _ in 0..200 {
+            let (v, slope) = f(x);
+            if v == 0.0 {
+                break;
+            }
+            if v < 0.0 {
+                a = x;
+            } else {
+                b = x;
+            }
+            let newton = x - v / slope;
+            let next = if newton > a && newton < b {
+                newton
+            } else {
+                0.5 * (a + b)
+            };
+            if next <= a || next >= b || (next - x).abs() <= f64::EPSILON * x.abs() {
+                x = next.clamp(a, b);
+                break;
+            }
+            x = next;
+        }
+        x
+    }
+
+    /// Probability that `X` lies on `branch` with `g(X)` below `y`, or above
+    /// it if `upper`.
+    fn part(&self, branch: &Branch, y: f64, upper: bool) -> f64 {
+        let (bottom, top) = branch.range();
+        let (lo, hi) = (branch.lo, branch.hi);
+        if y <= bottom {
+            return if upper { self.mass(lo, hi) } else { 0.0 };
+        }
+        if y >= top {
+            return if upper { 0.0 } else { self.mass(lo, hi) };
+        }
+        let x = self.preimage(branch, y);
+        // below y lies the part before x where g rises, after it where it falls
+        if branch.rising != upper {
+            self.mass(lo, x)
+        } else {
+            self.mass(x, hi)
+        }
+    }
+
+    /// Probability that `X` lies between `lo` and `hi`, from whichever tail
+    /// keeps the precision.
+    fn mass(&self, lo: f64, hi: f64) -> f64 {
+        let below = self.distr.cdf(hi);
+        let p = if below <= 0.5 {
+            below - self.distr.cdf(lo)
+        } else {
+            self.distr.sf(lo) - self.distr.sf(hi)
+        };
+        p.max(0.0)
+    }
+}
+
+/// The branches of `g` over the range `(min, max)` of `X`, from its values
+/// `ys` at the sorted points `xs`. A branch ends at a turning point, where
+/// `g'` changes sign, or where `g` jumps against its slope, as it does
+/// across a pole or a stretch where it is undefined. `None` if there are
+/// more than `MAX_BRANCHES`.
+fn branches(g: &Expr, (min, max): (f64, f64), xs: &[f64], ys: &[f64]) -> Option<Vec<Branch>> {
+    let slope = |x: f64| g.eval_with_derivative(x).1;
+    // where one branch ends and the next starts, and whether g rises on each
+    let mut cuts: Vec<(f64, f64)> = Vec::new();
+    let mut rising: Vec<bool> = Vec::new();
+    // the last interval over which g is not flat
+    let mut last: Option<usize> = None;
+    for i in 0..xs.len() - 1 {
+        let (a, b) = (xs[i], xs[i + 1]);
+        let (da, db) = (slope(a), slope(b));
+        let step = ys[i + 1] - ys[i];
+        let pole = da * db > 0.0 && step * da < 0.0;
+        let up = if pole {
+            da > 0.0
+        } else if step > 0.0 {
+            true
+        } else if step < 0.0 {
+            false
+        } else {
+            continue;
+        };
+        match last {
+            None => rising.push(up),
+            Some(j) if rising.last() != Some(&up) => {
+                let x = turning(slope, xs[j], b, !up).unwrap_or(a);
+                cuts.push((x, x));
+                rising.push(up);
+            }
+            Some(_) => {}
+        }
+        if pole {
+            cuts.push((reach(g, a, b, up), reach(g, b, a, up)));
+            rising.push(up);
+        }
+        if rising.len() > MAX_BRANCHES {
+            return None;
+        }
+        last = Some(i);
+    }
+
+    // at an end of the support where g is undefined, such as sin(x) at
+    // infinity, it stays where it is at the outermost point
+    let end = |x: f64, outermost: f64| {
+        let y = g.eval(x);
+        if y.is_nan() { outermost } else { y }
+    };
+    let starts = std::iter::once(min).chain(cuts.iter().map(|c| c.1));
+    let ends = cuts.iter().map(|c| c.0).chain(std::iter::once(max));
+    let branches = starts
+        .zip(ends)
+        .zip(rising)
+        .map(|((lo, hi), rising)| Branch {
+            lo,
+            hi,
+            g_lo: end(lo, ys[0]),
+            g_hi: end(hi, ys[ys.len() - 1]),
+            rising,
+        })
+        .collect();
+    Some(branches)
+}
+
+/// Where `slope` turns from the sign of `rising` to the other between `a`
+/// and `b`, by bisection. `None` if it does not at the ends.
+fn turning(slope: impl Fn(f64) -> f64, mut a: f64, mut b: f64, rising: bool) -> Option<f64> {
+    let before = |x: f64| {
+        let s = slope(x);
+        if rising { s > 0.0 } else { s < 0.0 }
+    };
+    let after = |x: f64| {
+        let s = slope(x);
+        if rising { s < 0.0 } else { s > 0.0 }
+    };
+    if !before(a) || !after(b) {
+        return None;
+    }
+    for _ in 0..200 {
+        let mid = 0.5 * (a + b);
+        if mid <= a || mid >= b {
+            break;
+        }
+        if before(mid) {
+            a = mid;
+        } else {
+            b = mid;
+        }
+    }
+    Some(0.5 * (a + b))
+}
+
+/// The point closest to `to` that `g` gets to from `from` while rising, or
+/// falling, and defined all the way, by bisection.
+fn reach(g: &Expr, from: f64, to: f64, rising: bool) -> f64 {
+    let (mut near, mut far) = (from, to);
+    let mut value = g.eval(from);
+    for _ in 0..200 {
+        let mid = 0.5 * (near + far);
+        if mid == near || mid == far {
+            break;
+        }
+        let y = g.eval(mid);
+        // to the right, a rising g grows, to the left it shrinks
+        if !y.is_nan() && ((y > value) == rising) == (mid > near) {
+            (near, value) = (mid, y);
+        } else {
+            far = mid;
+        }
+    }
+    near
+}
+
+/// Windows `[xᵢ₋₁, xᵢ₊₁]` around the draws where `|g|` peaks. A `g` that
+/// grows without bound inside the range of `X`, as `1/x` does about zero,
+/// shows up as one of them.
+fn peaks(draws: &[f64], values: &[f64]) -> Vec<(f64, f64)> {
+    (1..draws.len() - 1)
+        .filter(|&i| {
+            let v = values[i].abs();
+            v > values[i - 1].abs() && v >= values[i + 1].abs()
+        })
+        .map(|i| (draws[i - 1], draws[i + 1]))
+        .collect()
+}
+
+/// Mean, variance and skewness of `g(X)` from `E[g(X)^k] = ∫ g(x)^k f(x) dx`.
+///
+/// A moment is left out where it does not converge within the outermost
+/// `1e-9` of `X` on either side or the surroundings of a peak of `|g|`.
+fn moments(
+    distr: &dyn Cont,
+    g: &Expr,
+    tails: &[f64],
+    peaks: &[(f64, f64)],
+) -> (Option<f64>, Option<f64>, Option<f64>) {
+    let last = tails.len() - 1;
+    let mut points = tails.to_vec();
+    points.extend(peaks.iter().flat_map(|&(lo, hi)| [lo, hi]));
+    let mut suspect = vec![(tails[0], tails[1]), (tails[last - 1], tails[last])];
+    suspect.extend(peaks);
+    let moment = |r: i32| {
+        let integrand = |x: f64| {
+            let p = distr.pdf(x);
+            if p > 0.0 { g.eval(x).powi(r) * p } else { 0.0 }
+        };
+        converged_integral(integrand, points.clone(), &suspect)
+    };
+    moments_from_raw([moment(1), moment(2), moment(3)])
+}
+
+/// Gaussian kernel density estimate from draws, reflected at the smallest
+/// and largest of them so that no mass leaks past either. The bandwidth is
+/// Silverman's rule of thumb.
+struct Kde {
+    draws: Vec<f64>,
+    bandwidth: f64,
+    lo: f64,
+    hi: f64,
+    // unnormalized CDF at lo and the mass between lo and hi
+    base: f64,
+    mass: f64,
+}
+
+/// Kernels further than this many bandwidths away are taken to vanish.
+const KERNEL_REACH: f64 = 7.0;
+
+impl Kde {
+    fn new(mut draws: Vec<f64>) -> Option<Kde> {
+        draws.sort_by(f64::total_cmp);
+        let n = draws.len() as f64;
+        let mean = draws.iter().sum::<f64>() / n;
+        let sd = (draws.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+        let iqr = draws[3 * draws.len() / 4] - draws[draws.len() / 4];
+        let spread = if iqr > 0.0 { sd.min(iqr / 1.34) } else { sd };
+        let bandwidth = 0.9 * spread * n.powf(-0.2);
+        if !bandwidth.is_finite() || bandwidth <= 0.0 {
+            return None;
+        }
+        let mut kde = Kde {
+            lo: draws[0],
+            hi: draws[draws.len() - 1],
+            draws,
+            bandwidth,
+            base: 0.0,
+            mass: 1.0,
+        };
+        kde.base = kde.reflected_cdf(kde.lo);
+        kde.mass = kde.reflected_cdf(kde.hi) - kde.base;
+        Some(kde)
+    }
+
+    /// Draws below the reach of the kernels at `y` and those within it.
+    fn window(&self, y: f64) -> (usize, &[f64]) {
+        let reach = KERNEL_REACH * self.bandwidth;
+        let start = self.draws.partition_point(|&d| d < y - reach);
+        let end = self.draws.partition_point(|&d| d <= y + reach);
+        (start, &self.draws[start..end])
+    }
+
+    /// `Σ φ((y - dᵢ) / h)`
+    fn kernels(&self, y: f64) -> f64 {
+        let (_, near) = self.window(y);
+        near.iter()
+            .map(|d| {
+                let z = (y - d) / self.bandwidth;
+                (-0.5 * z * z).exp() / (2.0 * PI).sqrt()
+            })
+            .sum()
+    }
+
+    /// `Σ Φ((y - dᵢ) / h)`
+    fn integrals(&self, y: f64) -> f64 {
+        let (below, near) = self.window(y);
+        below as f64
+            + near
+                .iter()
+                .map(|d| std_normal_cdf((y - d) / self.bandwidth))
+                .sum::<f64>()
+    }
+
+    /// Unnormalized CDF with the kernels mirrored at both ends.
+    fn reflected_cdf(&self, y: f64) -> f64 {
+        let n = self.draws.len() as f64;
+        self.integrals(y)
+            + (n - self.integrals(2.0 * self.lo - y))
+            + (n - self.integrals(2.0 * self.hi - y))
+    }
+
+    fn pdf(&self, y: f64) -> f64 {
+        if !(self.lo..=self.hi).contains(&y) {
+            return 0.0;
+        }
+        let sum =
+            self.kernels(y) + self.kernels(2.0 * self.lo - y) + self.kernels(2.0 * self.hi - y);
+        sum / (self.bandwidth * self.mass)
+    }
+
+    fn cdf(&self, y: f64) -> f64 {
+        let y = y.clamp(self.lo, self.hi);
+        ((self.reflected_cdf(y) - self.base) / self.mass).clamp(0.0, 1.0)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Transformed {
+    fn cdf(&self, y: f64) -> f64 {
+        match &self.shape {
+            _ if y < self.min => 0.0,
+            _ if y >= self.max => 1.0,
+            Shape::Branches(branches) => branches
+                .iter()
+                .map(|b| self.part(b, y, false))
+                .sum::<f64>()
+                .min(1.0),
+            Shape::Estimated(table) => table.cdf(y),
+        }
+    }
+
+    fn sf(&self, y: f64) -> f64 {
+        match &self.shape {
+            _ if y < self.min => 1.0,
+            _ if y >= self.max => 0.0,
+            Shape::Branches(branches) => branches
+                .iter()
+                .map(|b| self.part(b, y, true))
+                .sum::<f64>()
+                .min(1.0),
+            Shape::Estimated(table) => 1.0 - table.cdf(y),
+        }
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min;
+        }
+        if p >= 1.0 {
+            return self.max;
+        }
+        match &self.shape {
+            Shape::Branches(branches) => match branches[..] {
+                [branch] if branch.rising => self.g.eval(self.distr.inverse_cdf(p)),
+                [_] => self.g.eval(self.distr.inverse_cdf(1.0 - p)),
+                _ => invert_cdf(|y| self.cdf(y), p, self.spread.0, self.spread.1),
+            },
+            Shape::Estimated(table) => table.inverse_cdf(p),
+        }
+    }
+}
+
+impl Min<f64> for Transformed {
+    fn min(&self) -> f64 {
+        self.min
+    }
+}
+
+impl Max<f64> for Transformed {
+    fn max(&self) -> f64 {
+        self.max
+    }
+}
+
+impl Distribution<f64> for Transformed {
+    fn mean(&self) -> Option<f64> {
+        self.moments.0
+    }
+
+    fn variance(&self) -> Option<f64> {
+        self.moments.1
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        Some(self.entropy)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        self.moments.2
+    }
+}
+
+impl Continuous<f64, f64> for Transformed {
+    fn pdf(&self, y: f64) -> f64 {
+        let branches = match &self.shape {
+            _ if !(y > self.min && y < self.max) => return 0.0,
+            Shape::Branches(branches) => branches,
+            Shape::Estimated(table) => return table.pdf(y),
+        };
+        branches
+            .iter()
+            .filter(|b| {
+                let (bottom, top) = b.range();
+                y > bottom && y < top
+            })
+            .map(|b| {
+                let x = self.preimage(b, y);
+                let (_, slope) = self.g.eval_with_derivative(x);
+                self.distr.pdf(x) / slope.abs()
+            })
+            .sum()
+    }
+
+    fn ln_pdf(&self, y: f64) -> f64 {
+        self.pdf(y).ln()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use statrs::distribution::{ChiSquared, LogNormal, Normal};
+
+    fn transformed(text: &str) -> Transformed {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        Transformed::new(Box::new(normal), Expr::parse(text).unwrap()).unwrap()
+    }
+
+    fn phi(x: f64) -> f64 {
+        (-0.5 * x * x).exp() / (2.0 * PI).sqrt()
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        let tolerance = 1e-7 * expected.abs().max(1e-3);
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{actual} is not {expected}"
+        );
+    }
+
+    #[test]
+    fn exp_is_log_normal() {
+        let t = transformed("exp(x)");
+        let exact = LogNormal::new(0.0, 1.0).unwrap();
+        for y in [0.05, 0.2, 1.0, 3.0, 20.0] {
+            assert_close(t.pdf(y), exact.pdf(y));
+            assert_close(t.cdf(y), exact.cdf(y));
+            assert_close(t.sf(y), exact.sf(y));
+        }
+        assert_close(t.inverse_cdf(0.9), exact.inverse_cdf(0.9));
+        assert_close(t.mean().unwrap(), exact.mean().unwrap());
+        assert_close(t.variance().unwrap(), exact.variance().unwrap());
+        assert_close(t.entropy().unwrap(), exact.entropy().unwrap());
+    }
+
+    #[test]
+    fn square_is_chi_squared() {
+        let t = transformed("x^2");
+        let exact = ChiSquared::new(1.0).unwrap();
+        // g turns at the x the bisection gets down to, not at exactly zero
+        assert!(t.min() >= 0.0 && t.min() < 1e-12);
+        assert_eq!(t.max(), f64::INFINITY);
+        for y in [0.01, 0.2, 1.0, 3.0, 12.0] {
+            assert_close(t.pdf(y), exact.pdf(y));
+            assert_close(t.cdf(y), exact.cdf(y));
+            assert_close(t.sf(y), exact.sf(y));
+        }
+        assert_close(t.inverse_cdf(0.3), exact.inverse_cdf(0.3));
+        assert_close(t.mean().unwrap(), 1.0);
+        assert_close(t.variance().unwrap(), 2.0);
+        assert_close(t.entropy().unwrap(), exact.entropy().unwrap());
+    }
+
+    #[test]
+    fn abs_is_half_normal() {
+        let t = transformed("abs(x)");
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        for y in [0.01, 0.2, 1.0, 3.0] {
+            assert_close(t.pdf(y), 2.0 * phi(y));
+            assert_close(t.cdf(y), 2.0 * normal.cdf(y) - 1.0);
+        }
+        assert_eq!(t.pdf(-0.5), 0.0);
+        assert_close(t.mean().unwrap(), (2.0 / PI).sqrt());
+        assert_close(t.variance().unwrap(), 1.0 - 2.0 / PI);
+        assert_close(t.entropy().unwrap(), 0.5 * (PI / 2.0).ln() + 0.5);
+    }
+
+    #[test]
+    fn reciprocal_has_no_moments() {
+        let t = transformed("1/x");
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        for y in [-2.0, -0.5, 0.2, 1.0, 3.0] {
+            assert_close(t.pdf(y), phi(1.0 / y) / (y * y));
+        }
+        for y in [-2.0, -0.5, 0.5, 3.0] {
+            // 1/X lies below y < 0 for X in (1/y, 0), and for y > 0 also
+            // wherever X is negative or above 1/y
+            let exact = if y < 0.0 {
+                normal.cdf(0.0) - normal.cdf(1.0 / y)
+            } else {
+                normal.cdf(0.0) + normal.sf(1.0 / y)
+            };
+            assert_close(t.cdf(y), exact);
+        }
+        assert_eq!(t.mean(), None);
+        assert_eq!(t.variance(), None);
+    }
+
+    #[test]
+    fn turning_points_split_the_range() {
+        // three branches, with g turning at -1 and 1
+        let t = transformed("x^3 - 3x");
+        let Shape::Branches(branches) = &t.shape else {
+            panic!("the density of x^3 - 3x is estimated");
+        };
+        assert_eq!(branches.len(), 3);
+        assert_close(branches[0].hi, -1.0);
+        assert_close(branches[2].lo, 1.0);
+        let y: f64 = 0.5;
+        // x = 2cos(θ) turns x^3 - 3x into 2cos(3θ)
+        let roots = [0.0, 1.0, 2.0].map(|k| 2.0 * (((y / 2.0).acos() + 2.0 * PI * k) / 3.0).cos());
+        let exact: f64 = roots
+            .iter()
+            .map(|x| phi(*x) / (3.0 * x * x - 3.0).abs())
+            .sum();
+        assert_close(t.pdf(y), exact);
+    }
+}
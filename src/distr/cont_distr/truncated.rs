@@ -0,0 +1,201 @@
+use super::Cont;
+use crate::distr::numeric::{QUANTILE_LEVELS, integrate_pieces, invert_cdf};
+use statrs::{
+    distribution::{Continuous, ContinuousCDF},
+    statistics::{Distribution, Max, Min},
+};
+use std::cell::OnceCell;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum TruncatedError {
+    BoundsInvalid,
+    NoMass,
+}
+
+impl std::fmt::Display for TruncatedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TruncatedError::BoundsInvalid => {
+                write!(f, "Lower or upper bound is NaN or lower >= upper")
+            }
+            TruncatedError::NoMass => write!(f, "The distribution has no mass between the bounds"),
+        }
+    }
+}
+
+impl std::error::Error for TruncatedError {}
+
+/// A distribution conditioned on `[a, b]`, with its density, CDF and quantiles
+/// renormalized to the mass it has there.
+///
+/// Summary statistics have no closed form in general and are integrated
+/// numerically. An unbounded side only has the moments of the original
+/// distribution.
+pub struct Truncated<D: ?Sized> {
+    lower: f64,
+    upper: f64,
+    mass: f64,
+    breakpoints: OnceCell<Vec<f64>>,
+    distr: Box<D>,
+}
+
+impl<D: Cont + ?Sized> Truncated<D> {
+    pub fn new(distr: Box<D>, lower: f64, upper: f64) -> Result<Truncated<D>, TruncatedError> {
+        if lower.is_nan() || upper.is_nan() || lower >= upper {
+            return Err(TruncatedError::BoundsInvalid);
+        }
+        let (lower, upper) = (lower.max(distr.min()), upper.min(distr.max()));
+        let mut truncated = Truncated {
+            lower,
+            upper,
+            mass: 1.0,
+            breakpoints: OnceCell::new(),
+            distr,
+        };
+        truncated.mass = truncated.between(lower, upper);
+        if truncated.mass <= 0.0 {
+            return Err(TruncatedError::NoMass);
+        }
+        Ok(truncated)
+    }
+
+    /// Mass of the original distribution in `[a, b]`, taken from whichever
+    /// tail keeps it from cancelling to zero.
+    fn between(&self, a: f64, b: f64) -> f64 {
+        if a >= b {
+            return 0.0;
+        }
+        let d = &self.distr;
+        if d.cdf(a) > 0.5 {
+            (d.sf(a) - d.sf(b)).max(0.0)
+        } else {
+            (d.cdf(b) - d.cdf(a)).max(0.0)
+        }
+    }
+
+    /// Integral of `f` over the support, split at quantiles of the truncated
+    /// distribution.
+    fn integral(&self, f: impl Fn(f64) -> f64) -> f64 {
+        let points = self.breakpoints.get_or_init(|| {
+            let start = self.distr.cdf(self.lower);
+            QUANTILE_LEVELS
+                .iter()
+                .map(|q| {
+                    self.distr
+                        .inverse_cdf(start + q * self.mass)
+                        .clamp(self.lower, self.upper)
+                })
+                .chain([self.lower, self.upper])
+                .collect()
+        });
+        integrate_pieces(f, points.clone())
+    }
+
+    /// `E[(X - c)^r]`, `None` if the unbounded sides leave it undefined.
+    fn moment(&self, r: i32, c: f64, exists: bool) -> Option<f64> {
+        let bounded = self.lower.is_finite() && self.upper.is_finite();
+        if !(exists || bounded) {
+            return None;
+        }
+        Some(self.integral(|x| (x - c).powi(r) * self.pdf(x)))
+    }
+}
+
+impl<D: Cont + ?Sized> ContinuousCDF<f64, f64> for Truncated<D> {
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.lower {
+            return 0.0;
+        }
+        if x >= self.upper {
+            return 1.0;
+        }
+        (self.between(self.lower, x) / self.mass).min(1.0)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.lower {
+            return 1.0;
+        }
+        if x >= self.upper {
+            return 0.0;
+        }
+        (self.between(x, self.upper) / self.mass).min(1.0)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if p <= 0.0 {
+            return self.min();
+        }
+        if p >= 1.0 {
+            return self.max();
+        }
+        // the quantile of the original distribution is a good first guess,
+        // bisection then recovers the precision lost in its tails
+        let guess = self
+            .distr
+            .inverse_cdf(self.distr.cdf(self.lower) + p * self.mass)
+            .clamp(self.lower, self.upper);
+        let lo = if self.lower.is_finite() {
+            self.lower
+        } else {
+            guess
+        };
+        let hi = if self.upper.is_finite() {
+            self.upper
+        } else {
+            guess
+        };
+        invert_cdf(|x| self.cdf(x), p, lo, hi)
+    }
+}
+
+impl<D: Cont + ?Sized> Min<f64> for Truncated<D> {
+    fn min(&self) -> f64 {
+        self.lower
+    }
+}
+
+impl<D: Cont + ?Sized> Max<f64> for Truncated<D> {
+    fn max(&self) -> f64 {
+        self.upper
+    }
+}
+
+impl<D: Cont + ?Sized> Distribution<f64> for Truncated<D> {
+    fn mean(&self) -> Option<f64> {
+        self.moment(1, 0.0, self.distr.mean().is_some())
+    }
+
+    fn variance(&self) -> Option<f64> {
+        let mean = self.mean()?;
+        self.moment(2, mean, self.distr.variance().is_some())
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        let entropy = self.integral(|x| {
+            let f = self.pdf(x);
+            if f > 0.0 { -f * f.ln() } else { 0.0 }
+        });
+        Some(entropy)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        let mean = self.mean()?;
+        let var = self.variance()?;
+        let third = self.moment(3, mean, self.distr.skewness().is_some())?;
+        Some(third / var.powf(1.5))
+    }
+}
+
+impl<D: Cont + ?Sized> Continuous<f64, f64> for Truncated<D> {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.lower || x > self.upper {
+            return 0.0;
+        }
+        self.distr.pdf(x) / self.mass
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
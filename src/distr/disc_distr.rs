@@ -1,8 +1,36 @@
 use super::{BARELY_POSITIVE, MixedParam, Param};
-use statrs::{distribution::*, statistics::Distribution};
+use statrs::{
+    distribution::*,
+    statistics::{DiscreteDistribution, Distribution, Max, Min},
+};
 use std::{fmt::Display, ops::RangeInclusive};
 use strum_macros::EnumIter;
 
+mod beta_binomial;
+mod compound;
+mod convolution;
+mod hypergeometric;
+mod logarithmic;
+mod mixture;
+mod order_statistic;
+mod poisson_binomial;
+mod power_law;
+mod table;
+mod two_sided;
+mod zero_modified;
+pub use beta_binomial::*;
+pub use compound::*;
+pub use convolution::*;
+pub use hypergeometric::*;
+pub use logarithmic::*;
+pub use mixture::*;
+pub use order_statistic::*;
+pub use poisson_binomial::*;
+pub use power_law::*;
+use table::Table;
+pub use two_sided::*;
+pub use zero_modified::*;
+
 #[derive(Debug, PartialEq, PartialOrd, EnumIter, Clone)]
 pub enum DistrTypes {
     Poisson,
@@ -10,7 +38,19 @@ pub enum DistrTypes {
     Bernoulli,
     Geometric,
     Hypergeometric,
-    // NegativeBinomial,
+    NegativeBinomial,
+    DiscreteUniform,
+    Categorical,
+    BetaBinomial,
+    Zipf,
+    Zeta,
+    Logarithmic,
+    YuleSimon,
+    Skellam,
+    DiscreteLaplace,
+    PoissonBinomial,
+    NegativeHypergeometric,
+    MultivariateHypergeometric,
 }
 
 impl DistrTypes {
@@ -69,7 +109,7 @@ impl DistrTypes {
                         default: 500,
                         range: RangeInclusive::new(1, u64::MAX),
                         name: "Population".to_owned(),
-                        desc: Some("0 < Population <= Successes".to_owned()),
+                        desc: Some(">0, >= Successes and Draws".to_owned()),
                         speed: 1.,
                     },
                 },
@@ -92,22 +132,244 @@ impl DistrTypes {
                     },
                 },
             ],
-            // DistrTypes::NegativeBinomial => vec![
-            //     MixedParam::Float {
-            //         param: Param {
-            //             default: 4.0,
-            //             range: RangeInclusive::new(0_f64, f64::MAX),
-            //             name: "r".to_owned(),
-            //         },
-            //     },
-            //     MixedParam::Float {
-            //         param: Param {
-            //             default: 0.5_f64,
-            //             range: RangeInclusive::new(0_f64, 1.0),
-            //             name: "p".to_owned(),
-            //         },
-            //     },
-            // ],
+            DistrTypes::NegativeBinomial => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 4.0,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "r".to_owned(),
+                        desc: Some(">0, number of successes to wait for (real-valued)".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 0.5_f64,
+                        range: RangeInclusive::new(BARELY_POSITIVE, 1.0),
+                        name: "p".to_owned(),
+                        desc: Some("0 < p <= 1. X = failures before the r-th success".to_owned()),
+                        speed: 0.05,
+                    },
+                },
+            ],
+            DistrTypes::DiscreteUniform => vec![
+                MixedParam::Signed {
+                    param: Param {
+                        default: 0,
+                        range: RangeInclusive::new(i64::MIN, i64::MAX),
+                        name: "Min".to_owned(),
+                        desc: Some("Min <= Max".to_owned()),
+                        speed: 1.,
+                    },
+                },
+                MixedParam::Signed {
+                    param: Param {
+                        default: 5,
+                        range: RangeInclusive::new(i64::MIN, i64::MAX),
+                        name: "Max".to_owned(),
+                        desc: Some(">=Min".to_owned()),
+                        speed: 1.,
+                    },
+                },
+            ],
+            DistrTypes::Categorical => vec![MixedParam::FloatList {
+                param: Param {
+                    default: 1_f64,
+                    range: RangeInclusive::new(0_f64, f64::MAX),
+                    name: "Weights".to_owned(),
+                    desc: Some(">=0, normalized to sum to 1".to_owned()),
+                    speed: 0.1,
+                },
+                default: vec![1., 2., 3., 2., 1.],
+            }],
+            DistrTypes::BetaBinomial => vec![
+                MixedParam::Unsigned {
+                    param: Param {
+                        default: 10,
+                        range: RangeInclusive::new(0, u64::MAX),
+                        name: "n".to_owned(),
+                        desc: Some(">=0".to_owned()),
+                        speed: 1.,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 2.0,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Alpha".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 3.0,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Beta".to_owned(),
+                        desc: Some(">0".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::Zipf => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 1.0,
+                        range: RangeInclusive::new(0.0, f64::MAX),
+                        name: "s".to_owned(),
+                        desc: Some(">=0".to_owned()),
+                        speed: 0.05,
+                    },
+                },
+                MixedParam::Unsigned {
+                    param: Param {
+                        default: 20,
+                        range: RangeInclusive::new(1, u64::MAX),
+                        name: "N".to_owned(),
+                        desc: Some(">=1, number of elements".to_owned()),
+                        speed: 1.,
+                    },
+                },
+            ],
+            DistrTypes::Zeta => vec![MixedParam::Float {
+                param: Param {
+                    default: 2.0,
+                    range: RangeInclusive::new(1.0 + BARELY_POSITIVE, f64::MAX),
+                    name: "s".to_owned(),
+                    desc: Some(">1".to_owned()),
+                    speed: 0.05,
+                },
+            }],
+            DistrTypes::Logarithmic => vec![MixedParam::Float {
+                param: Param {
+                    default: 0.8,
+                    range: RangeInclusive::new(BARELY_POSITIVE, 1.0 - BARELY_POSITIVE),
+                    name: "p".to_owned(),
+                    desc: Some("0 < p < 1".to_owned()),
+                    speed: 0.05,
+                },
+            }],
+            DistrTypes::YuleSimon => vec![MixedParam::Float {
+                param: Param {
+                    default: 2.0,
+                    range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                    name: "Rho".to_owned(),
+                    desc: Some(">0".to_owned()),
+                    speed: 0.1,
+                },
+            }],
+            DistrTypes::Skellam => vec![
+                MixedParam::Float {
+                    param: Param {
+                        default: 3.0,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Mu1".to_owned(),
+                        desc: Some(">0, mean of the first Poisson".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 2.0,
+                        range: RangeInclusive::new(BARELY_POSITIVE, f64::MAX),
+                        name: "Mu2".to_owned(),
+                        desc: Some(">0, mean of the subtracted Poisson".to_owned()),
+                        speed: 0.1,
+                    },
+                },
+            ],
+            DistrTypes::DiscreteLaplace => vec![
+                MixedParam::Signed {
+                    param: Param {
+                        default: 0,
+                        range: RangeInclusive::new(i64::MIN, i64::MAX),
+                        name: "Location".to_owned(),
+                        desc: None,
+                        speed: 1.,
+                    },
+                },
+                MixedParam::Float {
+                    param: Param {
+                        default: 0.5,
+                        range: RangeInclusive::new(BARELY_POSITIVE, 1.0 - BARELY_POSITIVE),
+                        name: "p".to_owned(),
+                        desc: Some("0 < p < 1".to_owned()),
+                        speed: 0.05,
+                    },
+                },
+            ],
+            DistrTypes::PoissonBinomial => vec![MixedParam::FloatList {
+                param: Param {
+                    default: 0.5,
+                    range: RangeInclusive::new(0.0, 1.0),
+                    name: "Probabilities".to_owned(),
+                    desc: Some("0 <= p <= 1, success probability of one trial".to_owned()),
+                    speed: 0.01,
+                },
+                default: vec![0.1, 0.3, 0.5, 0.7, 0.9],
+            }],
+            DistrTypes::NegativeHypergeometric => vec![
+                MixedParam::Unsigned {
+                    param: Param {
+                        default: 50,
+                        range: RangeInclusive::new(1, u64::MAX),
+                        name: "Population".to_owned(),
+                        desc: Some(">0, >= Successes + Failures".to_owned()),
+                        speed: 1.,
+                    },
+                },
+                MixedParam::Unsigned {
+                    param: Param {
+                        default: 20,
+                        range: RangeInclusive::new(0, u64::MAX),
+                        name: "Successes".to_owned(),
+                        desc: Some("0 <= Successes <= Population".to_owned()),
+                        speed: 1.,
+                    },
+                },
+                MixedParam::Unsigned {
+                    param: Param {
+                        default: 5,
+                        range: RangeInclusive::new(1, u64::MAX),
+                        name: "Failures".to_owned(),
+                        desc: Some(
+                            "0 < Failures <= Population - Successes. X = successes before the last failure"
+                                .to_owned(),
+                        ),
+                        speed: 1.,
+                    },
+                },
+            ],
+            DistrTypes::MultivariateHypergeometric => vec![
+                MixedParam::UnsignedList {
+                    param: Param {
+                        default: 10,
+                        range: RangeInclusive::new(0, MAX_COUNT),
+                        name: "Counts".to_owned(),
+                        desc: Some(">=0, number of elements in the category".to_owned()),
+                        speed: 1.,
+                    },
+                    default: vec![20, 30, 50],
+                },
+                MixedParam::Unsigned {
+                    param: Param {
+                        default: 10,
+                        range: RangeInclusive::new(0, u64::MAX),
+                        name: "Draws".to_owned(),
+                        desc: Some("0 <= Draws <= sum of Counts".to_owned()),
+                        speed: 1.,
+                    },
+                },
+                MixedParam::Unsigned {
+                    param: Param {
+                        default: 0,
+                        range: RangeInclusive::new(0, u64::MAX),
+                        name: "Category".to_owned(),
+                        desc: Some("Index of the category to show the count of".to_owned()),
+                        speed: 1.,
+                    },
+                },
+            ],
         }
     }
 }
@@ -119,15 +381,198 @@ impl Display for DistrTypes {
             DistrTypes::Bernoulli => write!(f, "Bernoulli"),
             DistrTypes::Geometric => write!(f, "Geometric"),
             DistrTypes::Hypergeometric => write!(f, "Hypergeometric"),
-            // DistrTypes::NegativeBinomial => write!(f, "NegativeBinomial"),
+            DistrTypes::NegativeBinomial => write!(f, "NegativeBinomial"),
+            DistrTypes::DiscreteUniform => write!(f, "DiscreteUniform"),
+            DistrTypes::Categorical => write!(f, "Categorical"),
+            DistrTypes::BetaBinomial => write!(f, "BetaBinomial"),
+            DistrTypes::Zipf => write!(f, "Zipf"),
+            DistrTypes::Zeta => write!(f, "Zeta"),
+            DistrTypes::Logarithmic => write!(f, "Logarithmic"),
+            DistrTypes::YuleSimon => write!(f, "YuleSimon"),
+            DistrTypes::Skellam => write!(f, "Skellam"),
+            DistrTypes::DiscreteLaplace => write!(f, "DiscreteLaplace"),
+            DistrTypes::PoissonBinomial => write!(f, "PoissonBinomial"),
+            DistrTypes::NegativeHypergeometric => write!(f, "NegativeHypergeometric"),
+            DistrTypes::MultivariateHypergeometric => write!(f, "MultivariateHypergeometric"),
+        }
+    }
+}
+
+pub trait Disc: Discrete<i64, f64> + DiscreteCDF<i64, f64> + Distribution<f64> {}
+impl<D> Disc for NonNegative<D> where
+    D: Discrete<u64, f64> + DiscreteCDF<u64, f64> + Distribution<f64>
+{
+}
+impl Disc for Compound {}
+impl Disc for Convolution {}
+impl Disc for DiscreteUniform {}
+impl Disc for Mixture {}
+impl Disc for OrderStatistic {}
+impl Disc for Skellam {}
+impl Disc for DiscreteLaplace {}
+impl Disc for ZeroModified {}
+
+/// Smallest `k` with `cdf(k) >= p`. The support may be unbounded on either
+/// side, so the quantile is bracketed by doubling steps away from 0 and then
+/// found by bisection, rather than by walking the CDF one value at a time.
+pub fn quantile(distr: &dyn Disc, p: f64) -> i64 {
+    let (bottom, top) = (distr.min(), distr.max());
+    let start = 0.clamp(bottom, top);
+    let (mut lo, mut hi) = (start, start);
+    let mut step = 1_i64;
+    if distr.cdf(start) >= p {
+        while lo > bottom && distr.cdf(lo) >= p {
+            hi = lo;
+            lo = lo.saturating_sub(step).max(bottom);
+            step = step.saturating_mul(2);
+        }
+        if distr.cdf(lo) >= p {
+            return lo;
+        }
+    } else {
+        while hi < top && distr.cdf(hi) < p {
+            lo = hi;
+            hi = hi.saturating_add(step).min(top);
+            step = step.saturating_mul(2);
+        }
+    }
+    // cdf(lo) < p <= cdf(hi)
+    while hi.abs_diff(lo) > 1 {
+        let mid = lo.midpoint(hi);
+        if distr.cdf(mid) < p {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    hi
+}
+
+/// Most values a sum over the support of a distribution adds up.
+const MAX_TERMS: u64 = 1_000_000;
+
+/// `Σ f(x, P(X = x))` over all values of `distr` but the outermost `1e-12`
+/// of its mass, `None` for tails too long to add up.
+pub(crate) fn central_sum(distr: &dyn Disc, f: impl Fn(i64, f64) -> f64) -> Option<f64> {
+    let (lo, hi) = (quantile(distr, 1e-12), quantile(distr, 1.0 - 1e-12));
+    if hi.abs_diff(lo) > MAX_TERMS {
+        return None;
+    }
+    Some((lo..=hi).map(|x| f(x, distr.pmf(x))).sum())
+}
+
+/// Entropy of `distr` summed over all values but the outermost `1e-12` of
+/// its mass, `None` for tails too long to add up.
+pub fn summed_entropy(distr: &dyn Disc) -> Option<f64> {
+    central_sum(distr, |_, p| if p > 0.0 { -p * p.ln() } else { 0.0 })
+}
+
+/// Lifts a distribution on the non-negative integers, as statrs and most of
+/// the families here define them, onto the i64 support of `Disc`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NonNegative<D>(pub D);
+
+impl<D: Discrete<u64, f64>> Discrete<i64, f64> for NonNegative<D> {
+    fn pmf(&self, x: i64) -> f64 {
+        if x < 0 { 0.0 } else { self.0.pmf(x as u64) }
+    }
+    fn ln_pmf(&self, x: i64) -> f64 {
+        if x < 0 {
+            f64::NEG_INFINITY
+        } else {
+            self.0.ln_pmf(x as u64)
         }
     }
 }
 
-pub trait Disc: Discrete<u64, f64> + DiscreteCDF<u64, f64> + Distribution<f64> {}
-impl Disc for Binomial {}
-impl Disc for Poisson {}
-impl Disc for Bernoulli {}
-impl Disc for Geometric {}
-impl Disc for Hypergeometric {}
-// impl Disc for NegativeBinomial {}
+impl<D: DiscreteCDF<u64, f64>> DiscreteCDF<i64, f64> for NonNegative<D> {
+    fn cdf(&self, x: i64) -> f64 {
+        if x < 0 { 0.0 } else { self.0.cdf(x as u64) }
+    }
+    fn sf(&self, x: i64) -> f64 {
+        if x < 0 { 1.0 } else { self.0.sf(x as u64) }
+    }
+}
+
+impl<D: Min<u64>> Min<i64> for NonNegative<D> {
+    fn min(&self) -> i64 {
+        self.0.min().min(i64::MAX as u64) as i64
+    }
+}
+
+impl<D: Max<u64>> Max<i64> for NonNegative<D> {
+    fn max(&self) -> i64 {
+        self.0.max().min(i64::MAX as u64) as i64
+    }
+}
+
+impl<D: Distribution<f64>> Distribution<f64> for NonNegative<D> {
+    fn mean(&self) -> Option<f64> {
+        self.0.mean()
+    }
+    fn variance(&self) -> Option<f64> {
+        self.0.variance()
+    }
+    fn entropy(&self) -> Option<f64> {
+        self.0.entropy()
+    }
+    fn skewness(&self) -> Option<f64> {
+        self.0.skewness()
+    }
+}
+
+/// statrs only implements `DiscreteDistribution` for `NegativeBinomial`,
+/// this wrapper provides the `Distribution` impl required by `Disc`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NegBinomial(NegativeBinomial);
+
+impl NegBinomial {
+    pub fn new(r: f64, p: f64) -> Result<NegBinomial, NegativeBinomialError> {
+        Ok(NegBinomial(NegativeBinomial::new(r, p)?))
+    }
+}
+
+impl Discrete<u64, f64> for NegBinomial {
+    fn pmf(&self, x: u64) -> f64 {
+        self.0.pmf(x)
+    }
+    fn ln_pmf(&self, x: u64) -> f64 {
+        self.0.ln_pmf(x)
+    }
+}
+
+impl DiscreteCDF<u64, f64> for NegBinomial {
+    fn cdf(&self, x: u64) -> f64 {
+        self.0.cdf(x)
+    }
+    fn sf(&self, x: u64) -> f64 {
+        self.0.sf(x)
+    }
+}
+
+impl Min<u64> for NegBinomial {
+    fn min(&self) -> u64 {
+        self.0.min()
+    }
+}
+
+impl Max<u64> for NegBinomial {
+    fn max(&self) -> u64 {
+        self.0.max()
+    }
+}
+
+impl Distribution<f64> for NegBinomial {
+    fn mean(&self) -> Option<f64> {
+        self.0.mean()
+    }
+    fn variance(&self) -> Option<f64> {
+        self.0.variance()
+    }
+    fn entropy(&self) -> Option<f64> {
+        summed_entropy(&NonNegative(*self))
+    }
+    fn skewness(&self) -> Option<f64> {
+        self.0.skewness()
+    }
+}
@@ -0,0 +1,145 @@
+use super::{NonNegative, summed_entropy};
+use crate::distr::numeric::integrate_pieces;
+use statrs::{
+    distribution::{Discrete, DiscreteCDF},
+    function::{
+        beta::{beta_reg, ln_beta},
+        gamma::ln_gamma,
+    },
+    statistics::{Distribution, Max, Min},
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum BetaBinomialError {
+    ShapeNotPositive,
+}
+
+impl std::fmt::Display for BetaBinomialError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BetaBinomialError::ShapeNotPositive => {
+                write!(f, "Alpha or Beta is NaN, infinite, zero or less than zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BetaBinomialError {}
+
+/// Beta-binomial distribution, the number of successes in `n` trials whose
+/// success probability is drawn from `Beta(α, β)`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BetaBinomial {
+    n: u64,
+    alpha: f64,
+    beta: f64,
+}
+
+impl BetaBinomial {
+    pub fn new(n: u64, alpha: f64, beta: f64) -> Result<BetaBinomial, BetaBinomialError> {
+        if !alpha.is_finite() || alpha <= 0.0 || !beta.is_finite() || beta <= 0.0 {
+            return Err(BetaBinomialError::ShapeNotPositive);
+        }
+        Ok(BetaBinomial { n, alpha, beta })
+    }
+}
+
+/// `P(U < V)` for independent `U ~ Beta(alpha, beta)` and `V ~ Beta(a, b)`,
+/// integrated over the density of `V` around its mean.
+fn below(alpha: f64, beta: f64, a: f64, b: f64) -> f64 {
+    let (mean, rest) = (a / (a + b), b / (a + b));
+    let sd = (mean * rest / (a + b + 1.0)).sqrt();
+    // log density relative to the mean, the terms on their own grow with
+    // `a` and `b` and would drown it in their rounding errors
+    let ln_at_mean = (a - 1.0) * mean.ln() + (b - 1.0) * rest.ln() - ln_beta(a, b);
+    let density = |v: f64| {
+        let d = v - mean;
+        ((a - 1.0) * (d / mean).ln_1p() + (b - 1.0) * (-d / rest).ln_1p() + ln_at_mean).exp()
+    };
+    let points = [-40.0, -8.0, -2.0, 0.0, 2.0, 8.0, 40.0]
+        .iter()
+        .map(|k| (mean + k * sd).clamp(0.0, 1.0))
+        .chain([0.0, 1.0])
+        .collect();
+    integrate_pieces(|v| density(v) * beta_reg(alpha, beta, v), points).clamp(0.0, 1.0)
+}
+
+impl Discrete<u64, f64> for BetaBinomial {
+    fn pmf(&self, x: u64) -> f64 {
+        if x > self.n {
+            return 0.0;
+        }
+        self.ln_pmf(x).exp()
+    }
+
+    fn ln_pmf(&self, x: u64) -> f64 {
+        if x > self.n {
+            return f64::NEG_INFINITY;
+        }
+        let (n, k) = (self.n as f64, x as f64);
+        let ln_binomial = ln_gamma(n + 1.0) - ln_gamma(k + 1.0) - ln_gamma(n - k + 1.0);
+        ln_binomial + ln_beta(k + self.alpha, n - k + self.beta) - ln_beta(self.alpha, self.beta)
+    }
+}
+
+/// Given the success probability `P`, at most `x` of the `n` trials succeed
+/// exactly when `P` is below the `(x + 1)`-th smallest of `n` uniforms, which
+/// is `Beta(x + 1, n - x)` distributed. Either tail is integrated on its own
+/// so that neither loses its precision to `1 - p`, at a cost that does not
+/// grow with `n`.
+impl DiscreteCDF<u64, f64> for BetaBinomial {
+    fn cdf(&self, x: u64) -> f64 {
+        if x >= self.n {
+            return 1.0;
+        }
+        let (a, b) = (x as f64 + 1.0, (self.n - x) as f64);
+        below(self.alpha, self.beta, a, b)
+    }
+
+    fn sf(&self, x: u64) -> f64 {
+        if x >= self.n {
+            return 0.0;
+        }
+        // P > V is 1 - P < 1 - V, with 1 - V ~ Beta(n - x, x + 1)
+        let (a, b) = (x as f64 + 1.0, (self.n - x) as f64);
+        below(self.beta, self.alpha, b, a)
+    }
+}
+
+impl Min<u64> for BetaBinomial {
+    fn min(&self) -> u64 {
+        0
+    }
+}
+
+impl Max<u64> for BetaBinomial {
+    fn max(&self) -> u64 {
+        self.n
+    }
+}
+
+impl Distribution<f64> for BetaBinomial {
+    fn mean(&self) -> Option<f64> {
+        Some(self.n as f64 * self.alpha / (self.alpha + self.beta))
+    }
+
+    fn variance(&self) -> Option<f64> {
+        let (n, a, b) = (self.n as f64, self.alpha, self.beta);
+        Some(n * a * b * (a + b + n) / ((a + b).powi(2) * (a + b + 1.0)))
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        summed_entropy(&NonNegative(*self))
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        let (n, a, b) = (self.n as f64, self.alpha, self.beta);
+        if n == 0.0 {
+            return None;
+        }
+        Some(
+            (a + b + 2.0 * n) * (b - a) / (a + b + 2.0)
+                * ((1.0 + a + b) / (n * a * b * (n + a + b))).sqrt(),
+        )
+    }
+}
@@ -0,0 +1,132 @@
+use super::{Disc, Table, quantile};
+use crate::distr::{
+    cont_distr::{CompoundError, Cont, compound_moments, mixing_points},
+    numeric::{integrate_pieces, raw_moments},
+};
+use statrs::{
+    distribution::{Discrete, DiscreteCDF},
+    statistics::{Distribution, Max, Min},
+};
+
+/// Widest range of values the probabilities are integrated for.
+const MAX_WIDTH: i64 = 2_000;
+
+/// Marginal distribution of `X` when a parameter `θ` of its distribution is
+/// itself drawn from a continuous mixing distribution,
+/// `P(X = k) = ∫ P(X = k | θ) π(θ) dθ`.
+///
+/// `family` gives the conditional distribution of `X` at every value of `θ`
+/// and `None` where the parameter is invalid. The probabilities are
+/// integrated over `θ` for all values but the outermost `1e-12` of the mass,
+/// or more for tails too long to integrate over. The moments follow from
+/// those of the conditional distributions.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Compound {
+    table: Table,
+    min: i64,
+    max: i64,
+    moments: (Option<f64>, Option<f64>, Option<f64>),
+}
+
+impl Compound {
+    pub fn new(
+        family: impl Fn(f64) -> Option<Box<dyn Disc>>,
+        mixing: &dyn Cont,
+    ) -> Result<Compound, CompoundError> {
+        let points = mixing_points(mixing);
+        let conditionals = points
+            .iter()
+            .map(|&t| family(t))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(CompoundError::ParameterInvalid)?;
+
+        // the conditional distributions at either end of the mixing one
+        // bound the central values of the marginal
+        const TAILS: [f64; 5] = [1e-12, 1e-9, 1e-6, 1e-4, 1e-3];
+        let range = |eps: f64| -> Option<(i64, i64)> {
+            let ends = [
+                family(mixing.inverse_cdf(eps))?,
+                family(mixing.inverse_cdf(0.5))?,
+                family(mixing.inverse_cdf(1.0 - eps))?,
+            ];
+            let lo = ends.iter().map(|d| quantile(d.as_ref(), eps)).min()?;
+            let hi = ends.iter().map(|d| quantile(d.as_ref(), 1.0 - eps)).max()?;
+            Some((lo, hi))
+        };
+        let (lo, hi) = TAILS
+            .iter()
+            .filter_map(|&eps| range(eps))
+            .find(|(lo, hi)| hi.saturating_sub(*lo) <= MAX_WIDTH)
+            .or_else(|| {
+                let (lo, _) = range(TAILS[TAILS.len() - 1])?;
+                Some((lo, lo.saturating_add(MAX_WIDTH)))
+            })
+            .ok_or(CompoundError::ParameterInvalid)?;
+
+        let mass: Vec<f64> = (lo..=hi)
+            .map(|k| {
+                let integrand = |t: f64| family(t).map_or(0.0, |d| d.pmf(k) * mixing.pdf(t));
+                integrate_pieces(integrand, points.clone()).max(0.0)
+            })
+            .collect();
+
+        Ok(Compound {
+            table: Table::new(lo, mass),
+            min: conditionals.iter().map(|d| d.min()).min().unwrap_or(lo),
+            max: conditionals.iter().map(|d| d.max()).max().unwrap_or(hi),
+            moments: compound_moments(mixing, |t, r| {
+                family(t).and_then(|d| raw_moments(d.as_ref())[r - 1])
+            }),
+        })
+    }
+}
+
+impl Discrete<i64, f64> for Compound {
+    fn pmf(&self, x: i64) -> f64 {
+        self.table.pmf(x)
+    }
+
+    fn ln_pmf(&self, x: i64) -> f64 {
+        self.pmf(x).ln()
+    }
+}
+
+impl DiscreteCDF<i64, f64> for Compound {
+    fn cdf(&self, x: i64) -> f64 {
+        self.table.cdf(x)
+    }
+
+    fn sf(&self, x: i64) -> f64 {
+        self.table.sf(x)
+    }
+}
+
+impl Min<i64> for Compound {
+    fn min(&self) -> i64 {
+        self.min
+    }
+}
+
+impl Max<i64> for Compound {
+    fn max(&self) -> i64 {
+        self.max
+    }
+}
+
+impl Distribution<f64> for Compound {
+    fn mean(&self) -> Option<f64> {
+        self.moments.0
+    }
+
+    fn variance(&self) -> Option<f64> {
+        self.moments.1
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        Some(self.table.entropy())
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        self.moments.2
+    }
+}
@@ -0,0 +1,287 @@
+use super::{Disc, Table, quantile};
+use crate::distr::{
+    cont_distr::ConvolutionError,
+    numeric::{fft, sum_moments},
+};
+use statrs::{
+    distribution::{Discrete, DiscreteCDF},
+    statistics::{Distribution, Max, Min},
+};
+
+/// Mass left out at either end of the terms and the partial sums.
+const TAIL: f64 = 1e-15;
+
+/// Widest range of values a term or partial sum is kept over.
+const MAX_WIDTH: i64 = 10_000;
+
+/// Longest table convolved directly, longer ones are convolved by FFT.
+const DIRECT: usize = 64;
+
+/// Distribution of a sum of independent variables, each distribution paired
+/// with the number of times it is added.
+///
+/// The probabilities are convolved directly for short tables and by FFT for
+/// long ones. Only the outermost `1e-15` of the mass is dropped along the
+/// way, or more for tails too long to sum over. Mean, variance and skewness
+/// are exact.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Convolution {
+    table: Table,
+    min: i64,
+    max: i64,
+    moments: (Option<f64>, Option<f64>, Option<f64>),
+}
+
+/// Probabilities of consecutive values starting at an offset.
+type Pmf = (i64, Vec<f64>);
+
+impl Convolution {
+    pub fn new(terms: &[(&dyn Disc, u64)]) -> Result<Convolution, ConvolutionError> {
+        if terms.is_empty() {
+            return Err(ConvolutionError::NoTerms);
+        }
+        if terms.iter().any(|&(_, n)| n == 0) {
+            return Err(ConvolutionError::CountZero);
+        }
+        let (offset, mass) = terms
+            .iter()
+            .map(|&(d, n)| power(&central_pmf(d), n))
+            .reduce(|a, b| convolve(&a, &b))
+            .expect("at least one term");
+
+        let bound = |f: fn(&dyn Disc) -> i64| {
+            terms
+                .iter()
+                .map(|&(d, n)| f(d).saturating_mul(n.min(i64::MAX as u64) as i64))
+                .fold(0_i64, i64::saturating_add)
+        };
+        let parts: Vec<(u64, &dyn Distribution<f64>)> = terms
+            .iter()
+            .map(|&(d, n)| (n, d as &dyn Distribution<f64>))
+            .collect();
+        Ok(Convolution {
+            table: Table::new(offset, mass),
+            min: bound(|d| d.min()),
+            max: bound(|d| d.max()),
+            moments: sum_moments(&parts),
+        })
+    }
+}
+
+/// Probabilities of `d` over all values but the outermost `TAIL` of the
+/// mass, or more if that range is wider than `MAX_WIDTH`.
+fn central_pmf(d: &dyn Disc) -> Pmf {
+    const TAILS: [f64; 6] = [TAIL, 1e-12, 1e-9, 1e-6, 1e-4, 1e-3];
+    let range = |eps: f64| (quantile(d, eps), quantile(d, 1.0 - eps));
+    let (lo, hi) = TAILS
+        .iter()
+        .map(|&eps| range(eps))
+        .find(|(lo, hi)| hi.saturating_sub(*lo) <= MAX_WIDTH)
+        .unwrap_or_else(|| {
+            let (lo, _) = range(TAILS[TAILS.len() - 1]);
+            (lo, lo.saturating_add(MAX_WIDTH))
+        });
+    (lo, (lo..=hi).map(|k| d.pmf(k)).collect())
+}
+
+/// Distribution of the sum of `a` and `b`, without its negligible tails.
+fn convolve(a: &Pmf, b: &Pmf) -> Pmf {
+    let len = a.1.len() + b.1.len() - 1;
+    let mass = if a.1.len().min(b.1.len()) <= DIRECT {
+        let mut mass = vec![0.0; len];
+        for (i, &p) in a.1.iter().enumerate() {
+            for (j, &q) in b.1.iter().enumerate() {
+                mass[i + j] += p * q;
+            }
+        }
+        mass
+    } else {
+        let size = len.next_power_of_two();
+        let transform = |pmf: &[f64]| {
+            let (mut re, mut im) = (pmf.to_vec(), vec![0.0; size]);
+            re.resize(size, 0.0);
+            fft(&mut re, &mut im, false);
+            (re, im)
+        };
+        let ((mut re, mut im), (b_re, b_im)) = (transform(&a.1), transform(&b.1));
+        for k in 0..size {
+            (re[k], im[k]) = (
+                re[k] * b_re[k] - im[k] * b_im[k],
+                re[k] * b_im[k] + im[k] * b_re[k],
+            );
+        }
+        fft(&mut re, &mut im, true);
+        re.truncate(len);
+        // rounding leaves noise of either sign around zero in the tails
+        let peak = re.iter().fold(0.0_f64, |m, &p| m.max(p));
+        let noise = f64::EPSILON * (size as f64).log2() * peak;
+        re.into_iter()
+            .map(|p| if p > noise { p } else { 0.0 })
+            .collect()
+    };
+    cap(trim((a.0.saturating_add(b.0), mass)))
+}
+
+/// Distribution of the sum of `n` copies of `pmf`, by repeated squaring.
+fn power(pmf: &Pmf, n: u64) -> Pmf {
+    let mut result: Option<Pmf> = None;
+    let mut square = pmf.clone();
+    let mut n = n;
+    loop {
+        if n & 1 == 1 {
+            result = Some(match result {
+                None => square.clone(),
+                Some(r) => convolve(&r, &square),
+            });
+        }
+        n >>= 1;
+        if n == 0 {
+            break;
+        }
+        square = convolve(&square, &square);
+    }
+    result.expect("n is at least one")
+}
+
+/// Drops the values at either end holding less than `TAIL` of the mass.
+fn trim((offset, mass): Pmf) -> Pmf {
+    let cut = |iter: &mut dyn Iterator<Item = &f64>| {
+        let mut tail = 0.0;
+        iter.take_while(|&&p| {
+            tail += p;
+            tail < TAIL
+        })
+        .count()
+    };
+    let front = cut(&mut mass.iter());
+    if front == mass.len() {
+        return (offset, mass);
+    }
+    let back = cut(&mut mass.iter().rev());
+    (
+        offset.saturating_add(front as i64),
+        mass[front..mass.len() - back].to_vec(),
+    )
+}
+
+/// Keeps the `MAX_WIDTH + 1` consecutive values holding the most mass.
+fn cap((offset, mass): Pmf) -> Pmf {
+    let width = MAX_WIDTH as usize + 1;
+    if mass.len() <= width {
+        return (offset, mass);
+    }
+    let mut window: f64 = mass[..width].iter().sum();
+    let (mut best, mut start) = (window, 0);
+    for i in width..mass.len() {
+        window += mass[i] - mass[i - width];
+        if window > best {
+            (best, start) = (window, i + 1 - width);
+        }
+    }
+    (
+        offset.saturating_add(start as i64),
+        mass[start..start + width].to_vec(),
+    )
+}
+
+impl Discrete<i64, f64> for Convolution {
+    fn pmf(&self, x: i64) -> f64 {
+        self.table.pmf(x)
+    }
+
+    fn ln_pmf(&self, x: i64) -> f64 {
+        self.pmf(x).ln()
+    }
+}
+
+impl DiscreteCDF<i64, f64> for Convolution {
+    fn cdf(&self, x: i64) -> f64 {
+        self.table.cdf(x)
+    }
+
+    fn sf(&self, x: i64) -> f64 {
+        self.table.sf(x)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> i64 {
+        if p <= 0.0 {
+            return self.min;
+        }
+        self.table.inverse_cdf(p)
+    }
+}
+
+impl Min<i64> for Convolution {
+    fn min(&self) -> i64 {
+        self.min
+    }
+}
+
+impl Max<i64> for Convolution {
+    fn max(&self) -> i64 {
+        self.max
+    }
+}
+
+impl Distribution<f64> for Convolution {
+    fn mean(&self) -> Option<f64> {
+        self.moments.0
+    }
+
+    fn variance(&self) -> Option<f64> {
+        self.moments.1
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        Some(self.table.entropy())
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        self.moments.2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distr::disc_distr::NonNegative;
+    use statrs::distribution::Poisson;
+
+    fn poisson(lambda: f64) -> NonNegative<Poisson> {
+        NonNegative(Poisson::new(lambda).unwrap())
+    }
+
+    fn assert_close(actual: f64, expected: f64, relative: f64) {
+        assert!(
+            (actual - expected).abs() <= relative * expected,
+            "{actual} is not {expected}"
+        );
+    }
+
+    #[test]
+    fn sum_of_poissons_is_poisson() {
+        let (a, b) = (poisson(2.0), poisson(1.5));
+        let sum = Convolution::new(&[(&a, 1), (&b, 3)]).unwrap();
+        let exact = poisson(6.5);
+        for k in 0..20 {
+            assert_close(sum.pmf(k), exact.pmf(k), 1e-10);
+            assert_close(sum.cdf(k), exact.cdf(k), 1e-10);
+        }
+        assert_eq!(sum.pmf(-1), 0.0);
+        assert_eq!(sum.inverse_cdf(0.5), exact.inverse_cdf(0.5));
+        assert_close(sum.mean().unwrap(), 6.5, 1e-12);
+        assert_close(sum.variance().unwrap(), 6.5, 1e-12);
+    }
+
+    #[test]
+    fn long_sum_of_poissons_is_poisson() {
+        // long enough for the transforms
+        let sum = Convolution::new(&[(&poisson(1000.0), 50)]).unwrap();
+        let exact = poisson(50_000.0);
+        for k in [49_000, 49_800, 50_000, 50_300, 51_000] {
+            assert_close(sum.pmf(k), exact.pmf(k), 1e-8);
+            assert_close(sum.cdf(k), exact.cdf(k), 1e-8);
+        }
+        assert_close(sum.entropy().unwrap(), exact.entropy().unwrap(), 1e-8);
+    }
+}
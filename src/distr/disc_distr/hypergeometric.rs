@@ -0,0 +1,206 @@
+use super::{NonNegative, summed_entropy};
+use statrs::{
+    distribution::{Discrete, DiscreteCDF, Hypergeometric, HypergeometricError},
+    function::gamma::ln_gamma,
+    statistics::{Distribution, Max, Min},
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum SamplingError {
+    CategoryOutOfRange,
+    DrawsExceedPopulation,
+    FailuresInvalid,
+    PopulationTooLarge,
+    SuccessesExceedPopulation,
+}
+
+impl std::fmt::Display for SamplingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SamplingError::CategoryOutOfRange => write!(f, "Category is not one of the counts"),
+            SamplingError::DrawsExceedPopulation => write!(f, "Draws is greater than Population"),
+            SamplingError::FailuresInvalid => {
+                write!(f, "Failures is zero or greater than Population - Successes")
+            }
+            SamplingError::PopulationTooLarge => write!(f, "Counts add up to too many elements"),
+            SamplingError::SuccessesExceedPopulation => {
+                write!(f, "Successes is greater than Population")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SamplingError {}
+
+impl From<HypergeometricError> for SamplingError {
+    fn from(err: HypergeometricError) -> SamplingError {
+        match err {
+            HypergeometricError::TooManySuccesses => SamplingError::SuccessesExceedPopulation,
+            _ => SamplingError::DrawsExceedPopulation,
+        }
+    }
+}
+
+// ln C(n, k)
+fn ln_binomial(n: u64, k: u64) -> f64 {
+    let (n, k) = (n as f64, k as f64);
+    ln_gamma(n + 1.0) - ln_gamma(k + 1.0) - ln_gamma(n - k + 1.0)
+}
+
+/// Negative hypergeometric distribution, the number of successes drawn
+/// without replacement from a population of `N` with `K` successes before
+/// the `r`-th failure.
+#[derive(Clone, PartialEq, Debug)]
+pub struct NegativeHypergeometric {
+    population: u64,
+    successes: u64,
+    failures: u64,
+}
+
+impl NegativeHypergeometric {
+    pub fn new(
+        population: u64,
+        successes: u64,
+        failures: u64,
+    ) -> Result<NegativeHypergeometric, SamplingError> {
+        if successes > population {
+            return Err(SamplingError::SuccessesExceedPopulation);
+        }
+        if failures == 0 || failures > population - successes {
+            return Err(SamplingError::FailuresInvalid);
+        }
+        Ok(NegativeHypergeometric {
+            population,
+            successes,
+            failures,
+        })
+    }
+
+    /// `E[X^r]` summed over the support, which holds at most `K + 1` values.
+    fn raw_moment(&self, r: i32) -> f64 {
+        (0..=self.successes)
+            .map(|k| (k as f64).powi(r) * self.pmf(k))
+            .sum()
+    }
+}
+
+impl Discrete<u64, f64> for NegativeHypergeometric {
+    fn pmf(&self, x: u64) -> f64 {
+        if x > self.successes {
+            return 0.0;
+        }
+        self.ln_pmf(x).exp()
+    }
+
+    /// `P(X = k) = C(k + r - 1, k) C(N - r - k, K - k) / C(N, K)`
+    fn ln_pmf(&self, x: u64) -> f64 {
+        if x > self.successes {
+            return f64::NEG_INFINITY;
+        }
+        let (n, s, r) = (self.population, self.successes, self.failures);
+        ln_binomial(x + r - 1, x) + ln_binomial(n - r - x, s - x) - ln_binomial(n, s)
+    }
+}
+
+impl DiscreteCDF<u64, f64> for NegativeHypergeometric {
+    fn cdf(&self, x: u64) -> f64 {
+        if x >= self.successes {
+            return 1.0;
+        }
+        (0..=x).map(|k| self.pmf(k)).sum::<f64>().min(1.0)
+    }
+
+    fn sf(&self, x: u64) -> f64 {
+        if x >= self.successes {
+            return 0.0;
+        }
+        (x + 1..=self.successes)
+            .map(|k| self.pmf(k))
+            .sum::<f64>()
+            .min(1.0)
+    }
+}
+
+impl Min<u64> for NegativeHypergeometric {
+    fn min(&self) -> u64 {
+        0
+    }
+}
+
+impl Max<u64> for NegativeHypergeometric {
+    fn max(&self) -> u64 {
+        self.successes
+    }
+}
+
+impl Distribution<f64> for NegativeHypergeometric {
+    fn mean(&self) -> Option<f64> {
+        let (n, s, r) = (
+            self.population as f64,
+            self.successes as f64,
+            self.failures as f64,
+        );
+        Some(r * s / (n - s + 1.0))
+    }
+
+    fn variance(&self) -> Option<f64> {
+        let (n, s, r) = (
+            self.population as f64,
+            self.successes as f64,
+            self.failures as f64,
+        );
+        Some(r * s * (n + 1.0) * (n - s - r + 1.0) / ((n - s + 1.0).powi(2) * (n - s + 2.0)))
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        summed_entropy(&NonNegative(self.clone()))
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        let (m1, var) = (self.mean()?, self.variance()?);
+        if var == 0.0 {
+            return None;
+        }
+        let m3 = self.raw_moment(3);
+        Some((m3 - 3.0 * m1 * var - m1.powi(3)) / var.powf(1.5))
+    }
+}
+
+/// Multivariate hypergeometric distribution, the counts of each category
+/// when drawing without replacement from a population split into categories.
+/// Only its marginals are plotted, each of which is hypergeometric.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MultivariateHypergeometric {
+    counts: Vec<u64>,
+    population: u64,
+    draws: u64,
+}
+
+/// Largest count of a single category.
+pub const MAX_COUNT: u64 = 1_000_000_000;
+
+impl MultivariateHypergeometric {
+    pub fn new(counts: &[u64], draws: u64) -> Result<MultivariateHypergeometric, SamplingError> {
+        let population = counts
+            .iter()
+            .try_fold(0_u64, |sum, &c| sum.checked_add(c))
+            .ok_or(SamplingError::PopulationTooLarge)?;
+        if draws > population {
+            return Err(SamplingError::DrawsExceedPopulation);
+        }
+        Ok(MultivariateHypergeometric {
+            counts: counts.to_vec(),
+            population,
+            draws,
+        })
+    }
+
+    /// Number of draws from `category`, all other categories lumped together.
+    pub fn marginal(&self, category: usize) -> Result<Hypergeometric, SamplingError> {
+        let count = *self
+            .counts
+            .get(category)
+            .ok_or(SamplingError::CategoryOutOfRange)?;
+        Ok(Hypergeometric::new(self.population, count, self.draws)?)
+    }
+}
@@ -0,0 +1,131 @@
+use statrs::{
+    distribution::{Discrete, DiscreteCDF},
+    statistics::{Distribution, Max, Min},
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum LogarithmicError {
+    ProbabilityInvalid,
+}
+
+impl std::fmt::Display for LogarithmicError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogarithmicError::ProbabilityInvalid => write!(f, "p is NaN or not in (0, 1)"),
+        }
+    }
+}
+
+impl std::error::Error for LogarithmicError {}
+
+/// Logarithmic series distribution on `1, 2, ...` with `P(X = k) ∝ p^k / k`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Logarithmic {
+    p: f64,
+    // -1 / ln(1 - p), the normalizing constant
+    norm: f64,
+}
+
+impl Logarithmic {
+    pub fn new(p: f64) -> Result<Logarithmic, LogarithmicError> {
+        if !(p > 0.0 && p < 1.0) {
+            return Err(LogarithmicError::ProbabilityInvalid);
+        }
+        Ok(Logarithmic {
+            p,
+            norm: -1.0 / (-p).ln_1p(),
+        })
+    }
+
+    /// `E[X^r] / norm = sum_k k^(r-1) p^k`
+    fn raw_moment(&self, r: i32) -> f64 {
+        let (p, q) = (self.p, 1.0 - self.p);
+        let sum = match r {
+            1 => p / q,
+            2 => p / (q * q),
+            _ => p * (1.0 + p) / q.powi(3),
+        };
+        self.norm * sum
+    }
+}
+
+impl Discrete<u64, f64> for Logarithmic {
+    fn pmf(&self, x: u64) -> f64 {
+        if x == 0 {
+            return 0.0;
+        }
+        self.ln_pmf(x).exp()
+    }
+
+    fn ln_pmf(&self, x: u64) -> f64 {
+        if x == 0 {
+            return f64::NEG_INFINITY;
+        }
+        let k = x as f64;
+        k * self.p.ln() - k.ln() + self.norm.ln()
+    }
+}
+
+impl DiscreteCDF<u64, f64> for Logarithmic {
+    fn cdf(&self, x: u64) -> f64 {
+        // the mode is at 1, so the CDF never gets small enough to lose its
+        // precision to 1 - sf
+        1.0 - self.sf(x)
+    }
+
+    fn sf(&self, x: u64) -> f64 {
+        // the terms fall off faster than p^k, stop once they no longer count
+        let mut sum = 0.0;
+        let mut k = x.saturating_add(1);
+        loop {
+            let term = self.pmf(k);
+            sum += term;
+            if term <= sum * f64::EPSILON || k == u64::MAX {
+                return sum.min(1.0);
+            }
+            k += 1;
+        }
+    }
+}
+
+impl Min<u64> for Logarithmic {
+    fn min(&self) -> u64 {
+        1
+    }
+}
+
+impl Max<u64> for Logarithmic {
+    fn max(&self) -> u64 {
+        u64::MAX
+    }
+}
+
+impl Distribution<f64> for Logarithmic {
+    fn mean(&self) -> Option<f64> {
+        Some(self.raw_moment(1))
+    }
+
+    fn variance(&self) -> Option<f64> {
+        let m1 = self.raw_moment(1);
+        Some(self.raw_moment(2) - m1 * m1)
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        let mut entropy = 0.0;
+        let mut k = 1;
+        loop {
+            let p = self.pmf(k);
+            entropy -= p * p.ln();
+            if p <= f64::EPSILON * 1e-3 && k as f64 > self.raw_moment(1) {
+                return Some(entropy);
+            }
+            k += 1;
+        }
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        let (m1, m2, m3) = (self.raw_moment(1), self.raw_moment(2), self.raw_moment(3));
+        let var = m2 - m1 * m1;
+        Some((m3 - 3.0 * m1 * var - m1.powi(3)) / var.powf(1.5))
+    }
+}
@@ -0,0 +1,108 @@
+use super::{Disc, summed_entropy};
+use crate::distr::{
+    cont_distr::{MixtureError, normalize_weights},
+    numeric::mixture_moments,
+};
+use statrs::{
+    distribution::{Discrete, DiscreteCDF},
+    statistics::{Distribution, Max, Min},
+};
+use std::rc::Rc;
+
+/// Finite mixture `Σ wᵢ Pᵢ` of discrete distributions, the weights are
+/// normalized to sum to one.
+///
+/// The entropy has no closed form and is summed once, when the mixture is
+/// built.
+#[derive(Clone)]
+pub struct Mixture {
+    weights: Vec<f64>,
+    components: Vec<Rc<dyn Disc>>,
+    moments: (Option<f64>, Option<f64>, Option<f64>),
+    entropy: Option<f64>,
+}
+
+impl Mixture {
+    pub fn new(components: Vec<(f64, Box<dyn Disc>)>) -> Result<Mixture, MixtureError> {
+        let (weights, components): (Vec<_>, Vec<_>) = components.into_iter().unzip();
+        let mut mixture = Mixture {
+            weights: normalize_weights(&weights)?,
+            components: components.into_iter().map(Rc::from).collect(),
+            moments: (None, None, None),
+            entropy: None,
+        };
+        mixture.moments = mixture.moments();
+        mixture.entropy = summed_entropy(&mixture);
+        Ok(mixture)
+    }
+
+    /// The components with their normalized weights.
+    pub fn components(&self) -> impl Iterator<Item = (f64, &dyn Disc)> {
+        self.weights
+            .iter()
+            .copied()
+            .zip(self.components.iter().map(|c| c.as_ref()))
+    }
+
+    fn weighted(&self, f: impl Fn(&dyn Disc) -> f64) -> f64 {
+        self.components().map(|(w, c)| w * f(c)).sum()
+    }
+
+    fn moments(&self) -> (Option<f64>, Option<f64>, Option<f64>) {
+        let parts: Vec<(f64, &dyn Distribution<f64>)> = self
+            .components()
+            .map(|(w, c)| (w, c as &dyn Distribution<f64>))
+            .collect();
+        mixture_moments(&parts)
+    }
+}
+
+impl Discrete<i64, f64> for Mixture {
+    fn pmf(&self, x: i64) -> f64 {
+        self.weighted(|c| c.pmf(x))
+    }
+
+    fn ln_pmf(&self, x: i64) -> f64 {
+        self.pmf(x).ln()
+    }
+}
+
+impl DiscreteCDF<i64, f64> for Mixture {
+    fn cdf(&self, x: i64) -> f64 {
+        self.weighted(|c| c.cdf(x)).clamp(0.0, 1.0)
+    }
+
+    fn sf(&self, x: i64) -> f64 {
+        self.weighted(|c| c.sf(x)).clamp(0.0, 1.0)
+    }
+}
+
+impl Min<i64> for Mixture {
+    fn min(&self) -> i64 {
+        self.components.iter().map(|c| c.min()).min().unwrap_or(0)
+    }
+}
+
+impl Max<i64> for Mixture {
+    fn max(&self) -> i64 {
+        self.components.iter().map(|c| c.max()).max().unwrap_or(0)
+    }
+}
+
+impl Distribution<f64> for Mixture {
+    fn mean(&self) -> Option<f64> {
+        self.moments.0
+    }
+
+    fn variance(&self) -> Option<f64> {
+        self.moments.1
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        self.entropy
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        self.moments.2
+    }
+}
@@ -0,0 +1,121 @@
+use super::{Disc, central_sum, summed_entropy};
+use crate::distr::{
+    cont_distr::{OrderStatisticError, rank_betas},
+    numeric::moments_from_raw,
+};
+use statrs::{
+    distribution::{Beta, ContinuousCDF, Discrete, DiscreteCDF},
+    statistics::{Distribution, Max, Min},
+};
+use std::rc::Rc;
+
+/// Distribution of the `k`-th smallest of `n` independent draws of a
+/// distribution, `k = 1` being the sample minimum and `k = n` the maximum.
+///
+/// The CDF is the regularized incomplete beta function `I_F(x)(k, n - k + 1)`,
+/// the probabilities are its differences. Moments and entropy are summed once
+/// over all values but the outermost `1e-12` of the mass, moments only where
+/// the original distribution has them.
+#[derive(Clone)]
+pub struct OrderStatistic {
+    lower: Beta,
+    upper: Beta,
+    distr: Rc<dyn Disc>,
+    moments: (Option<f64>, Option<f64>, Option<f64>),
+    entropy: Option<f64>,
+}
+
+impl OrderStatistic {
+    pub fn new(
+        distr: Box<dyn Disc>,
+        n: u64,
+        k: u64,
+    ) -> Result<OrderStatistic, OrderStatisticError> {
+        let (lower, upper) = rank_betas(n, k)?;
+        let mut order = OrderStatistic {
+            lower,
+            upper,
+            distr: Rc::from(distr),
+            moments: (None, None, None),
+            entropy: None,
+        };
+        order.moments = order.summed_moments();
+        order.entropy = summed_entropy(&order);
+        Ok(order)
+    }
+
+    fn summed_moments(&self) -> (Option<f64>, Option<f64>, Option<f64>) {
+        let exists = [
+            self.distr.mean().is_some(),
+            self.distr.variance().is_some(),
+            self.distr.skewness().is_some(),
+        ];
+        let raw = |r: i32| {
+            if exists[r as usize - 1] {
+                central_sum(self, |x, p| (x as f64).powi(r) * p)
+            } else {
+                None
+            }
+        };
+        moments_from_raw([raw(1), raw(2), raw(3)])
+    }
+}
+
+impl Discrete<i64, f64> for OrderStatistic {
+    /// A difference of whichever tail of the CDF keeps its precision.
+    fn pmf(&self, x: i64) -> f64 {
+        let Some(below) = x.checked_sub(1) else {
+            return self.cdf(x);
+        };
+        let p = if self.distr.cdf(below) < 0.5 {
+            self.cdf(x) - self.cdf(below)
+        } else {
+            self.sf(below) - self.sf(x)
+        };
+        p.max(0.0)
+    }
+
+    fn ln_pmf(&self, x: i64) -> f64 {
+        self.pmf(x).ln()
+    }
+}
+
+impl DiscreteCDF<i64, f64> for OrderStatistic {
+    fn cdf(&self, x: i64) -> f64 {
+        self.lower.cdf(self.distr.cdf(x))
+    }
+
+    fn sf(&self, x: i64) -> f64 {
+        self.upper.cdf(self.distr.sf(x))
+    }
+}
+
+impl Min<i64> for OrderStatistic {
+    fn min(&self) -> i64 {
+        self.distr.min()
+    }
+}
+
+impl Max<i64> for OrderStatistic {
+    fn max(&self) -> i64 {
+        self.distr.max()
+    }
+}
+
+impl Distribution<f64> for OrderStatistic {
+    fn mean(&self) -> Option<f64> {
+        self.moments.0
+    }
+
+    fn variance(&self) -> Option<f64> {
+        self.moments.1
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        self.entropy
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        self.moments.2
+    }
+}
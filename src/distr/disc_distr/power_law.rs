@@ -0,0 +1,267 @@
+use super::{NonNegative, summed_entropy};
+use statrs::{
+    distribution::{Discrete, DiscreteCDF},
+    function::beta::ln_beta,
+    statistics::{Distribution, Max, Min},
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum PowerLawError {
+    ElementsZero,
+    ExponentInvalid,
+    ShapeNotPositive,
+}
+
+impl std::fmt::Display for PowerLawError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PowerLawError::ElementsZero => write!(f, "Number of elements is zero"),
+            PowerLawError::ExponentInvalid => write!(
+                f,
+                "Exponent is NaN, infinite, less than zero or not above one without a number of elements"
+            ),
+            PowerLawError::ShapeNotPositive => {
+                write!(f, "Shape is NaN, infinite, zero or less than zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PowerLawError {}
+
+/// `sum_{k=from}^{to} k^-s`, with `to = None` for the infinite series. The
+/// first terms are added up directly, the rest from the Euler-Maclaurin
+/// formula so that long and infinite sums stay cheap.
+fn power_sum(from: u64, to: Option<u64>, s: f64) -> f64 {
+    const DIRECT: u64 = 1000;
+    let f = |k: f64| k.powf(-s);
+    let m = match to {
+        Some(n) if n < from.saturating_add(DIRECT) => {
+            return (from..=n).map(|k| f(k as f64)).sum();
+        }
+        _ => from + DIRECT,
+    };
+    let head: f64 = (from..m).map(|k| f(k as f64)).sum();
+    // f' and f''' scaled by the Bernoulli coefficients 1/12 and -1/720
+    let d1 = |x: f64| -s * x.powf(-s - 1.0) / 12.0;
+    let d3 = |x: f64| s * (s + 1.0) * (s + 2.0) * x.powf(-s - 3.0) / 720.0;
+    let m = m as f64;
+    let tail = match to {
+        Some(n) => {
+            let n = n as f64;
+            let integral = if s == 1.0 {
+                (n / m).ln()
+            } else {
+                (n.powf(1.0 - s) - m.powf(1.0 - s)) / (1.0 - s)
+            };
+            integral + (f(m) + f(n)) / 2.0 + d1(n) - d1(m) + d3(n) - d3(m)
+        }
+        None => m.powf(1.0 - s) / (s - 1.0) + f(m) / 2.0 - d1(m) - d3(m),
+    };
+    head + tail
+}
+
+/// Zipf distribution on `1..=N` with `P(X = k) ∝ k^-s`. Without an upper
+/// bound `N` it is the zeta distribution, which needs `s > 1` and whose
+/// `r`-th moment only exists for `s > r + 1`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Zipf {
+    s: f64,
+    n: Option<u64>,
+    norm: f64,
+}
+
+impl Zipf {
+    pub fn new(s: f64, n: u64) -> Result<Zipf, PowerLawError> {
+        if !s.is_finite() || s < 0.0 {
+            return Err(PowerLawError::ExponentInvalid);
+        }
+        if n == 0 {
+            return Err(PowerLawError::ElementsZero);
+        }
+        Ok(Zipf {
+            s,
+            n: Some(n),
+            norm: power_sum(1, Some(n), s),
+        })
+    }
+
+    /// Zeta distribution, the Zipf distribution on all positive integers.
+    pub fn zeta(s: f64) -> Result<Zipf, PowerLawError> {
+        if !s.is_finite() || s <= 1.0 {
+            return Err(PowerLawError::ExponentInvalid);
+        }
+        Ok(Zipf {
+            s,
+            n: None,
+            norm: power_sum(1, None, s),
+        })
+    }
+
+    /// `E[X^r] = H(N, s - r) / H(N, s)`
+    fn raw_moment(&self, r: f64) -> Option<f64> {
+        if self.n.is_none() && self.s - r <= 1.0 {
+            return None;
+        }
+        Some(power_sum(1, self.n, self.s - r) / self.norm)
+    }
+}
+
+impl Discrete<u64, f64> for Zipf {
+    fn pmf(&self, x: u64) -> f64 {
+        if x == 0 || x > self.max() {
+            return 0.0;
+        }
+        (x as f64).powf(-self.s) / self.norm
+    }
+
+    fn ln_pmf(&self, x: u64) -> f64 {
+        self.pmf(x).ln()
+    }
+}
+
+impl DiscreteCDF<u64, f64> for Zipf {
+    fn cdf(&self, x: u64) -> f64 {
+        if x == 0 {
+            return 0.0;
+        }
+        if x >= self.max() {
+            return 1.0;
+        }
+        (power_sum(1, Some(x), self.s) / self.norm).min(1.0)
+    }
+
+    fn sf(&self, x: u64) -> f64 {
+        if x == 0 {
+            return 1.0;
+        }
+        if x >= self.max() {
+            return 0.0;
+        }
+        (power_sum(x + 1, self.n, self.s) / self.norm).min(1.0)
+    }
+}
+
+impl Min<u64> for Zipf {
+    fn min(&self) -> u64 {
+        1
+    }
+}
+
+impl Max<u64> for Zipf {
+    fn max(&self) -> u64 {
+        self.n.unwrap_or(u64::MAX)
+    }
+}
+
+impl Distribution<f64> for Zipf {
+    fn mean(&self) -> Option<f64> {
+        self.raw_moment(1.0)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        let m1 = self.raw_moment(1.0)?;
+        Some(self.raw_moment(2.0)? - m1 * m1)
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        summed_entropy(&NonNegative(*self))
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        let m1 = self.raw_moment(1.0)?;
+        let var = self.variance()?;
+        let m3 = self.raw_moment(3.0)?;
+        Some((m3 - 3.0 * m1 * var - m1.powi(3)) / var.powf(1.5))
+    }
+}
+
+/// Yule-Simon distribution on `1, 2, ...` with shape `ρ`,
+/// `P(X = k) = ρ B(k, ρ + 1)`. Its tail falls off like `k^-(ρ+1)`, so the
+/// `r`-th moment only exists for `ρ > r`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct YuleSimon {
+    rho: f64,
+}
+
+impl YuleSimon {
+    pub fn new(rho: f64) -> Result<YuleSimon, PowerLawError> {
+        if !rho.is_finite() || rho <= 0.0 {
+            return Err(PowerLawError::ShapeNotPositive);
+        }
+        Ok(YuleSimon { rho })
+    }
+}
+
+impl Discrete<u64, f64> for YuleSimon {
+    fn pmf(&self, x: u64) -> f64 {
+        if x == 0 {
+            return 0.0;
+        }
+        self.ln_pmf(x).exp()
+    }
+
+    fn ln_pmf(&self, x: u64) -> f64 {
+        if x == 0 {
+            return f64::NEG_INFINITY;
+        }
+        self.rho.ln() + ln_beta(x as f64, self.rho + 1.0)
+    }
+}
+
+impl DiscreteCDF<u64, f64> for YuleSimon {
+    fn cdf(&self, x: u64) -> f64 {
+        1.0 - self.sf(x)
+    }
+
+    /// `P(X > k) = k B(k, ρ + 1)`
+    fn sf(&self, x: u64) -> f64 {
+        if x == 0 {
+            return 1.0;
+        }
+        let k = x as f64;
+        (k.ln() + ln_beta(k, self.rho + 1.0)).exp()
+    }
+}
+
+impl Min<u64> for YuleSimon {
+    fn min(&self) -> u64 {
+        1
+    }
+}
+
+impl Max<u64> for YuleSimon {
+    fn max(&self) -> u64 {
+        u64::MAX
+    }
+}
+
+impl Distribution<f64> for YuleSimon {
+    fn mean(&self) -> Option<f64> {
+        let rho = self.rho;
+        if rho <= 1.0 {
+            return None;
+        }
+        Some(rho / (rho - 1.0))
+    }
+
+    fn variance(&self) -> Option<f64> {
+        let rho = self.rho;
+        if rho <= 2.0 {
+            return None;
+        }
+        Some(rho * rho / ((rho - 1.0).powi(2) * (rho - 2.0)))
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        summed_entropy(&NonNegative(*self))
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        let rho = self.rho;
+        if rho <= 3.0 {
+            return None;
+        }
+        Some((rho + 1.0).powi(2) * (rho - 2.0).sqrt() / ((rho - 3.0) * rho))
+    }
+}
@@ -0,0 +1,79 @@
+/// Probabilities of consecutive values from an offset on, normalized to sum
+/// to one, together with their running sums. Values outside the table have
+/// none.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct Table {
+    offset: i64,
+    mass: Vec<f64>,
+    cumulative: Vec<f64>,
+}
+
+impl Table {
+    pub(crate) fn new(offset: i64, mut mass: Vec<f64>) -> Table {
+        let total: f64 = mass.iter().sum();
+        mass.iter_mut().for_each(|p| *p /= total);
+        let cumulative = mass
+            .iter()
+            .scan(0.0, |acc, p| {
+                *acc += p;
+                Some(*acc)
+            })
+            .collect();
+        Table {
+            offset,
+            mass,
+            cumulative,
+        }
+    }
+
+    fn index(&self, x: i64) -> Option<usize> {
+        let i = x.checked_sub(self.offset)?;
+        if i >= 0 && (i as usize) < self.mass.len() {
+            Some(i as usize)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn pmf(&self, x: i64) -> f64 {
+        self.index(x).map_or(0.0, |i| self.mass[i])
+    }
+
+    pub(crate) fn cdf(&self, x: i64) -> f64 {
+        if x < self.offset {
+            return 0.0;
+        }
+        match self.index(x) {
+            Some(i) => self.cumulative[i].min(1.0),
+            None => 1.0,
+        }
+    }
+
+    pub(crate) fn sf(&self, x: i64) -> f64 {
+        if x < self.offset {
+            return 1.0;
+        }
+        match self.index(x) {
+            Some(i) => self.mass[i + 1..].iter().sum(),
+            None => 0.0,
+        }
+    }
+
+    /// Smallest value in the table with `cdf(k) >= p`, the last one if
+    /// rounding keeps the running sums below `p`.
+    pub(crate) fn inverse_cdf(&self, p: f64) -> i64 {
+        let i = self
+            .cumulative
+            .partition_point(|&c| c < p)
+            .min(self.mass.len() - 1);
+        self.offset.saturating_add(i as i64)
+    }
+
+    pub(crate) fn entropy(&self) -> f64 {
+        self.mass
+            .iter()
+            .filter(|&&p| p > 0.0)
+            .map(|&p| -p * p.ln())
+            .sum()
+    }
+}
@@ -0,0 +1,196 @@
+use super::summed_entropy;
+use crate::distr::numeric::poisson_mixture;
+use statrs::{
+    distribution::{Discrete, DiscreteCDF},
+    function::gamma::{gamma_lr, gamma_ur, ln_gamma},
+    statistics::{Distribution, Max, Min},
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum TwoSidedError {
+    MeanNotPositive,
+    ProbabilityInvalid,
+}
+
+impl std::fmt::Display for TwoSidedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TwoSidedError::MeanNotPositive => {
+                write!(f, "Mean is NaN, infinite, zero or less than zero")
+            }
+            TwoSidedError::ProbabilityInvalid => write!(f, "p is NaN or not in (0, 1)"),
+        }
+    }
+}
+
+impl std::error::Error for TwoSidedError {}
+
+/// Skellam distribution, the difference `N1 - N2` of two independent Poisson
+/// variables with means `μ1` and `μ2`.
+///
+/// The PMF and CDF are Poisson mixtures over `N2`, which avoids Bessel
+/// functions of large order.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Skellam {
+    mu1: f64,
+    mu2: f64,
+}
+
+impl Skellam {
+    pub fn new(mu1: f64, mu2: f64) -> Result<Skellam, TwoSidedError> {
+        if !mu1.is_finite() || mu1 <= 0.0 || !mu2.is_finite() || mu2 <= 0.0 {
+            return Err(TwoSidedError::MeanNotPositive);
+        }
+        Ok(Skellam { mu1, mu2 })
+    }
+}
+
+impl Discrete<i64, f64> for Skellam {
+    fn pmf(&self, x: i64) -> f64 {
+        let mu = self.mu1;
+        poisson_mixture(self.mu2, |j| {
+            let n = x as f64 + j;
+            if n < 0.0 {
+                0.0
+            } else {
+                (-mu + n * mu.ln() - ln_gamma(n + 1.0)).exp()
+            }
+        })
+    }
+
+    fn ln_pmf(&self, x: i64) -> f64 {
+        self.pmf(x).ln()
+    }
+}
+
+impl DiscreteCDF<i64, f64> for Skellam {
+    fn cdf(&self, x: i64) -> f64 {
+        let mu = self.mu1;
+        let p = poisson_mixture(self.mu2, |j| {
+            let n = x as f64 + j;
+            if n < 0.0 { 0.0 } else { gamma_ur(n + 1.0, mu) }
+        });
+        p.clamp(0.0, 1.0)
+    }
+
+    fn sf(&self, x: i64) -> f64 {
+        let mu = self.mu1;
+        let p = poisson_mixture(self.mu2, |j| {
+            let n = x as f64 + j;
+            if n < 0.0 { 1.0 } else { gamma_lr(n + 1.0, mu) }
+        });
+        p.clamp(0.0, 1.0)
+    }
+}
+
+impl Min<i64> for Skellam {
+    fn min(&self) -> i64 {
+        i64::MIN
+    }
+}
+
+impl Max<i64> for Skellam {
+    fn max(&self) -> i64 {
+        i64::MAX
+    }
+}
+
+impl Distribution<f64> for Skellam {
+    fn mean(&self) -> Option<f64> {
+        Some(self.mu1 - self.mu2)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        Some(self.mu1 + self.mu2)
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        summed_entropy(self)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        Some((self.mu1 - self.mu2) / (self.mu1 + self.mu2).powf(1.5))
+    }
+}
+
+/// Discrete Laplace (two-sided geometric) distribution with integer location
+/// `μ` and decay `p`, `P(X = k) = (1 - p)/(1 + p) p^|k - μ|`. It is the
+/// difference of two independent geometric variables.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct DiscreteLaplace {
+    location: i64,
+    p: f64,
+}
+
+impl DiscreteLaplace {
+    pub fn new(location: i64, p: f64) -> Result<DiscreteLaplace, TwoSidedError> {
+        if !(p > 0.0 && p < 1.0) {
+            return Err(TwoSidedError::ProbabilityInvalid);
+        }
+        Ok(DiscreteLaplace { location, p })
+    }
+
+    // p^n
+    fn decay(&self, n: u64) -> f64 {
+        self.p.powf(n as f64)
+    }
+}
+
+impl Discrete<i64, f64> for DiscreteLaplace {
+    fn pmf(&self, x: i64) -> f64 {
+        (1.0 - self.p) / (1.0 + self.p) * self.decay(x.abs_diff(self.location))
+    }
+
+    fn ln_pmf(&self, x: i64) -> f64 {
+        ((1.0 - self.p) / (1.0 + self.p)).ln() + x.abs_diff(self.location) as f64 * self.p.ln()
+    }
+}
+
+impl DiscreteCDF<i64, f64> for DiscreteLaplace {
+    fn cdf(&self, x: i64) -> f64 {
+        if x < self.location {
+            self.decay(x.abs_diff(self.location)) / (1.0 + self.p)
+        } else {
+            1.0 - self.decay(x.abs_diff(self.location).saturating_add(1)) / (1.0 + self.p)
+        }
+    }
+
+    fn sf(&self, x: i64) -> f64 {
+        if x < self.location {
+            1.0 - self.decay(x.abs_diff(self.location)) / (1.0 + self.p)
+        } else {
+            self.decay(x.abs_diff(self.location).saturating_add(1)) / (1.0 + self.p)
+        }
+    }
+}
+
+impl Min<i64> for DiscreteLaplace {
+    fn min(&self) -> i64 {
+        i64::MIN
+    }
+}
+
+impl Max<i64> for DiscreteLaplace {
+    fn max(&self) -> i64 {
+        i64::MAX
+    }
+}
+
+impl Distribution<f64> for DiscreteLaplace {
+    fn mean(&self) -> Option<f64> {
+        Some(self.location as f64)
+    }
+
+    fn variance(&self) -> Option<f64> {
+        Some(2.0 * self.p / (1.0 - self.p).powi(2))
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        let p = self.p;
+        Some(((1.0 + p) / (1.0 - p)).ln() - 2.0 * p * p.ln() / (1.0 - p * p))
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        Some(0.0)
+    }
+}
@@ -0,0 +1,154 @@
+use super::{Disc, summed_entropy};
+use crate::distr::numeric::{moments_from_raw, raw_moments};
+use statrs::{
+    distribution::{Discrete, DiscreteCDF},
+    statistics::{Distribution, Max, Min},
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum ZeroModifiedError {
+    ProbabilityInvalid,
+    NoMassOffZero,
+}
+
+impl std::fmt::Display for ZeroModifiedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ZeroModifiedError::ProbabilityInvalid => {
+                write!(f, "Probability of zero must lie between 0 and 1")
+            }
+            ZeroModifiedError::NoMassOffZero => {
+                write!(
+                    f,
+                    "A hurdle needs a distribution that takes values other than 0"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ZeroModifiedError {}
+
+/// A distribution with the probability of zero changed and that of every
+/// other value scaled to make up the rest, `P(0) = p₀` and
+/// `P(k) = c f(k)` otherwise.
+///
+/// The zero-inflated variant adds structural zeros with probability `π` on
+/// top of those of the distribution, the hurdle variant has exactly `π` of
+/// the mass at zero and the distribution conditioned on being non-zero
+/// elsewhere. Raw moments are those of the distribution scaled by `c`.
+pub struct ZeroModified {
+    zero: f64,
+    scale: f64,
+    distr: Box<dyn Disc>,
+}
+
+impl ZeroModified {
+    /// `P(0) = π + (1 - π) f(0)` and `P(k) = (1 - π) f(k)` otherwise.
+    pub fn inflated(distr: Box<dyn Disc>, pi: f64) -> Result<ZeroModified, ZeroModifiedError> {
+        if !(0.0..=1.0).contains(&pi) {
+            return Err(ZeroModifiedError::ProbabilityInvalid);
+        }
+        Ok(ZeroModified {
+            zero: pi + (1.0 - pi) * distr.pmf(0),
+            scale: 1.0 - pi,
+            distr,
+        })
+    }
+
+    /// `P(0) = π` and `P(k) = (1 - π) f(k) / (1 - f(0))` otherwise.
+    pub fn hurdle(distr: Box<dyn Disc>, pi: f64) -> Result<ZeroModified, ZeroModifiedError> {
+        if !(0.0..=1.0).contains(&pi) {
+            return Err(ZeroModifiedError::ProbabilityInvalid);
+        }
+        // summed from the tails, 1 - f(0) cancels for f(0) close to 1
+        let off_zero = distr.cdf(-1) + distr.sf(0);
+        if off_zero <= 0.0 {
+            return Err(ZeroModifiedError::NoMassOffZero);
+        }
+        Ok(ZeroModified {
+            zero: pi,
+            scale: (1.0 - pi) / off_zero,
+            distr,
+        })
+    }
+
+    fn moments(&self) -> (Option<f64>, Option<f64>, Option<f64>) {
+        moments_from_raw(raw_moments(self.distr.as_ref()).map(|m| m.map(|m| self.scale * m)))
+    }
+}
+
+impl Discrete<i64, f64> for ZeroModified {
+    fn pmf(&self, x: i64) -> f64 {
+        if x == 0 {
+            self.zero
+        } else {
+            self.scale * self.distr.pmf(x)
+        }
+    }
+
+    fn ln_pmf(&self, x: i64) -> f64 {
+        self.pmf(x).ln()
+    }
+}
+
+/// Only values other than zero lie strictly below a negative `x` or above a
+/// non-negative one, so each side scales the matching tail of the
+/// distribution.
+impl DiscreteCDF<i64, f64> for ZeroModified {
+    fn cdf(&self, x: i64) -> f64 {
+        if x < 0 {
+            self.scale * self.distr.cdf(x)
+        } else {
+            1.0 - self.scale * self.distr.sf(x)
+        }
+        .clamp(0.0, 1.0)
+    }
+
+    fn sf(&self, x: i64) -> f64 {
+        if x < 0 {
+            1.0 - self.scale * self.distr.cdf(x)
+        } else {
+            self.scale * self.distr.sf(x)
+        }
+        .clamp(0.0, 1.0)
+    }
+}
+
+impl Min<i64> for ZeroModified {
+    fn min(&self) -> i64 {
+        if self.zero > 0.0 {
+            self.distr.min().min(0)
+        } else {
+            self.distr.min()
+        }
+    }
+}
+
+impl Max<i64> for ZeroModified {
+    fn max(&self) -> i64 {
+        if self.zero > 0.0 {
+            self.distr.max().max(0)
+        } else {
+            self.distr.max()
+        }
+    }
+}
+
+impl Distribution<f64> for ZeroModified {
+    fn mean(&self) -> Option<f64> {
+        self.moments().0
+    }
+
+    fn variance(&self) -> Option<f64> {
+        self.moments().1
+    }
+
+    fn entropy(&self) -> Option<f64> {
+        summed_entropy(self)
+    }
+
+    fn skewness(&self) -> Option<f64> {
+        self.moments().2
+    }
+}
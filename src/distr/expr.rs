@@ -0,0 +1,452 @@
+use std::f64::consts::{E, LN_2, LN_10, PI};
+
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum ExprError {
+    Empty,
+    UnexpectedChar(char),
+    UnexpectedEnd,
+    UnknownName(String),
+    ArgumentMissing(String),
+    ParenUnclosed,
+}
+
+impl std::fmt::Display for ExprError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExprError::Empty => write!(f, "Enter an expression in x"),
+            ExprError::UnexpectedChar(c) => write!(f, "Unexpected '{c}' in the expression"),
+            ExprError::UnexpectedEnd => write!(f, "The expression ends too early"),
+            ExprError::UnknownName(name) => {
+                write!(
+                    f,
+                    "Unknown name '{name}', use x, pi, e or a function such as exp"
+                )
+            }
+            ExprError::ArgumentMissing(name) => {
+                write!(f, "The argument of '{name}' must be in parentheses")
+            }
+            ExprError::ParenUnclosed => write!(f, "Missing closing parenthesis"),
+        }
+    }
+}
+
+impl std::error::Error for ExprError {}
+
+/// Function of a single variable `x`, parsed from text such as `exp(x)`,
+/// `x^2` or `1/(1 + x)`.
+///
+/// It knows `+ - * / ^` (also `**`), parentheses, the constants `pi` and `e`
+/// and the usual elementary functions. A product of a number and what
+/// follows it may be written without `*`, as in `2x`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Expr(Node);
+
+#[derive(Clone, PartialEq, Debug)]
+enum Node {
+    X,
+    Const(f64),
+    Neg(Box<Node>),
+    Add(Box<Node>, Box<Node>),
+    Sub(Box<Node>, Box<Node>),
+    Mul(Box<Node>, Box<Node>),
+    Div(Box<Node>, Box<Node>),
+    Pow(Box<Node>, Box<Node>),
+    Call(Func, Box<Node>),
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Func {
+    Exp,
+    Ln,
+    Log10,
+    Log2,
+    Sqrt,
+    Cbrt,
+    Abs,
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Sinh,
+    Cosh,
+    Tanh,
+    Asinh,
+    Acosh,
+    Atanh,
+}
+
+impl Expr {
+    pub fn parse(text: &str) -> Result<Expr, ExprError> {
+        if text.trim().is_empty() {
+            return Err(ExprError::Empty);
+        }
+        let mut parser = Parser {
+            chars: text.chars().collect(),
+            pos: 0,
+        };
+        let node = parser.sum()?;
+        match parser.peek() {
+            None => Ok(Expr(node)),
+            Some(c) => Err(ExprError::UnexpectedChar(c)),
+        }
+    }
+
+    pub fn eval(&self, x: f64) -> f64 {
+        self.0.dual(x).0
+    }
+
+    /// Value and derivative at `x`, the derivative is exact up to rounding.
+    pub fn eval_with_derivative(&self, x: f64) -> (f64, f64) {
+        self.0.dual(x)
+    }
+}
+
+impl Node {
+    /// Forward mode differentiation, every node passes on its value together
+    /// with its derivative with respect to `x`.
+    fn dual(&self, x: f64) -> (f64, f64) {
+        match self {
+            Node::X => (x, 1.0),
+            Node::Const(c) => (*c, 0.0),
+            Node::Neg(a) => {
+                let (a, da) = a.dual(x);
+                (-a, -da)
+            }
+            Node::Add(a, b) => {
+                let ((a, da), (b, db)) = (a.dual(x), b.dual(x));
+                (a + b, da + db)
+            }
+            Node::Sub(a, b) => {
+                let ((a, da), (b, db)) = (a.dual(x), b.dual(x));
+                (a - b, da - db)
+            }
+            Node::Mul(a, b) => {
+                let ((a, da), (b, db)) = (a.dual(x), b.dual(x));
+                (a * b, da * b + a * db)
+            }
+            Node::Div(a, b) => {
+                let ((a, da), (b, db)) = (a.dual(x), b.dual(x));
+                (a / b, (da * b - a * db) / (b * b))
+            }
+            Node::Pow(a, b) => {
+                let ((a, da), (b, db)) = (a.dual(x), b.dual(x));
+                let v = a.powf(b);
+                // a constant side drops out, so that a negative base with a
+                // constant exponent does not take the logarithm of it
+                let mut dv = 0.0;
+                if da != 0.0 {
+                    dv += b * a.powf(b - 1.0) * da;
+                }
+                if db != 0.0 {
+                    dv += v * a.ln() * db;
+                }
+                (v, dv)
+            }
+            Node::Call(f, a) => {
+                let (a, da) = a.dual(x);
+                let (v, slope) = f.eval(a);
+                (v, if da != 0.0 { slope * da } else { 0.0 })
+            }
+        }
+    }
+}
+
+impl Func {
+    fn from_name(name: &str) -> Option<Func> {
+        let f = match name {
+            "exp" => Func::Exp,
+            "ln" | "log" => Func::Ln,
+            "log10" => Func::Log10,
+            "log2" => Func::Log2,
+            "sqrt" => Func::Sqrt,
+            "cbrt" => Func::Cbrt,
+            "abs" => Func::Abs,
+            "sin" => Func::Sin,
+            "cos" => Func::Cos,
+            "tan" => Func::Tan,
+            "asin" => Func::Asin,
+            "acos" => Func::Acos,
+            "atan" => Func::Atan,
+            "sinh" => Func::Sinh,
+            "cosh" => Func::Cosh,
+            "tanh" => Func::Tanh,
+            "asinh" => Func::Asinh,
+            "acosh" => Func::Acosh,
+            "atanh" => Func::Atanh,
+            _ => return None,
+        };
+        Some(f)
+    }
+
+    /// Value and slope at `a`.
+    fn eval(self, a: f64) -> (f64, f64) {
+        match self {
+            Func::Exp => (a.exp(), a.exp()),
+            Func::Ln => (a.ln(), 1.0 / a),
+            Func::Log10 => (a.log10(), 1.0 / (a * LN_10)),
+            Func::Log2 => (a.log2(), 1.0 / (a * LN_2)),
+            Func::Sqrt => (a.sqrt(), 0.5 / a.sqrt()),
+            Func::Cbrt => (a.cbrt(), 1.0 / (3.0 * a.cbrt().powi(2))),
+            Func::Abs => (a.abs(), a.signum()),
+            Func::Sin => (a.sin(), a.cos()),
+            Func::Cos => (a.cos(), -a.sin()),
+            Func::Tan => (a.tan(), 1.0 + a.tan().powi(2)),
+            Func::Asin => (a.asin(), 1.0 / (1.0 - a * a).sqrt()),
+            Func::Acos => (a.acos(), -1.0 / (1.0 - a * a).sqrt()),
+            Func::Atan => (a.atan(), 1.0 / (1.0 + a * a)),
+            Func::Sinh => (a.sinh(), a.cosh()),
+            Func::Cosh => (a.cosh(), a.sinh()),
+            Func::Tanh => (a.tanh(), 1.0 - a.tanh().powi(2)),
+            Func::Asinh => (a.asinh(), 1.0 / (a * a + 1.0).sqrt()),
+            Func::Acosh => (a.acosh(), 1.0 / (a * a - 1.0).sqrt()),
+            Func::Atanh => (a.atanh(), 1.0 / (1.0 - a * a)),
+        }
+    }
+}
+
+/// Recursive descent over the characters of an expression. Each method
+/// parses one level of precedence.
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    /// The next character that is not whitespace.
+    fn peek(&mut self) -> Option<char> {
+        while self.raw().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+        self.raw()
+    }
+
+    /// The next character, which may end a number or a name.
+    fn raw(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    /// Terms joined by `+` and `-`.
+    fn sum(&mut self) -> Result<Node, ExprError> {
+        let mut node = self.product()?;
+        loop {
+            if self.eat('+') {
+                node = Node::Add(Box::new(node), Box::new(self.product()?));
+            } else if self.eat('-') {
+                node = Node::Sub(Box::new(node), Box::new(self.product()?));
+            } else {
+                return Ok(node);
+            }
+        }
+    }
+
+    /// Factors joined by `*`, `/` or written next to each other.
+    fn product(&mut self) -> Result<Node, ExprError> {
+        let mut node = self.signed()?;
+        loop {
+            if self.peek() == Some('*') && self.chars.get(self.pos + 1) != Some(&'*') {
+                self.pos += 1;
+                node = Node::Mul(Box::new(node), Box::new(self.signed()?));
+            } else if self.eat('/') {
+                node = Node::Div(Box::new(node), Box::new(self.signed()?));
+            } else if self.peek().is_some_and(|c| c.is_alphanumeric() || c == '(') {
+                node = Node::Mul(Box::new(node), Box::new(self.power()?));
+            } else {
+                return Ok(node);
+            }
+        }
+    }
+
+    /// A power with any number of signs in front, `-x^2` is `-(x^2)`.
+    fn signed(&mut self) -> Result<Node, ExprError> {
+        if self.eat('-') {
+            Ok(Node::Neg(Box::new(self.signed()?)))
+        } else if self.eat('+') {
+            self.signed()
+        } else {
+            self.power()
+        }
+    }
+
+    /// `^` and `**` bind to the right, `2^3^2` is `2^9`.
+    fn power(&mut self) -> Result<Node, ExprError> {
+        let base = self.atom()?;
+        let raised = if self.peek() == Some('*') && self.chars.get(self.pos + 1) == Some(&'*') {
+            self.pos += 2;
+            true
+        } else {
+            self.eat('^')
+        };
+        if raised {
+            Ok(Node::Pow(Box::new(base), Box::new(self.signed()?)))
+        } else {
+            Ok(base)
+        }
+    }
+
+    fn atom(&mut self) -> Result<Node, ExprError> {
+        match self.peek() {
+            None => Err(ExprError::UnexpectedEnd),
+            Some('(') => {
+                self.pos += 1;
+                self.enclosed()
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => self.number(),
+            Some(c) if c.is_alphabetic() => self.name(),
+            Some(c) => Err(ExprError::UnexpectedChar(c)),
+        }
+    }
+
+    /// The rest of a parenthesized expression, after the opening one.
+    fn enclosed(&mut self) -> Result<Node, ExprError> {
+        let node = self.sum()?;
+        if self.eat(')') {
+            Ok(node)
+        } else {
+            Err(ExprError::ParenUnclosed)
+        }
+    }
+
+    fn number(&mut self) -> Result<Node, ExprError> {
+        let start = self.pos;
+        let digits = |p: &mut Parser| {
+            while p.raw().is_some_and(|c| c.is_ascii_digit()) {
+                p.pos += 1;
+            }
+        };
+        digits(self);
+        if self.raw() == Some('.') {
+            self.pos += 1;
+            digits(self);
+        }
+        // an exponent only if digits follow, otherwise it is the constant
+        let at = |i: usize| self.chars.get(self.pos + i).copied();
+        if matches!(at(0), Some('e' | 'E')) {
+            let sign = usize::from(matches!(at(1), Some('+' | '-')));
+            if at(1 + sign).is_some_and(|c| c.is_ascii_digit()) {
+                self.pos += 1 + sign;
+                digits(self);
+            }
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse()
+            .map(Node::Const)
+            .map_err(|_| ExprError::UnexpectedChar('.'))
+    }
+
+    fn name(&mut self) -> Result<Node, ExprError> {
+        let start = self.pos;
+        while self.raw().is_some_and(|c| c.is_alphanumeric()) {
+            self.pos += 1;
+        }
+        let name: String = self.chars[start..self.pos]
+            .iter()
+            .collect::<String>()
+            .to_lowercase();
+        match name.as_str() {
+            "x" => return Ok(Node::X),
+            "pi" | "π" => return Ok(Node::Const(PI)),
+            "e" => return Ok(Node::Const(E)),
+            _ => {}
+        }
+        let Some(f) = Func::from_name(&name) else {
+            return Err(ExprError::UnknownName(name));
+        };
+        if !self.eat('(') {
+            return Err(ExprError::ArgumentMissing(name));
+        }
+        Ok(Node::Call(f, Box::new(self.enclosed()?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(text: &str, x: f64) -> f64 {
+        Expr::parse(text).unwrap().eval(x)
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(eval("1 + 2 * 3", 0.0), 7.0);
+        assert_eq!(eval("(1 + 2) * 3", 0.0), 9.0);
+        assert_eq!(eval("2 - 3 - 4", 0.0), -5.0);
+        assert_eq!(eval("12 / 2 / 3", 0.0), 2.0);
+        assert_eq!(eval("-x^2", 3.0), -9.0);
+        assert_eq!(eval("2^3^2", 0.0), 512.0);
+        assert_eq!(eval("2^-1", 0.0), 0.5);
+        assert_eq!(eval("--x", 2.0), 2.0);
+    }
+
+    #[test]
+    fn double_star_is_power() {
+        assert_eq!(eval("2 ** 3", 0.0), 8.0);
+        assert_eq!(eval("x**2 * 2", 3.0), 18.0);
+        assert_eq!(eval("2**3**2", 0.0), 512.0);
+    }
+
+    #[test]
+    fn implicit_products() {
+        assert_eq!(eval("2x", 3.0), 6.0);
+        assert_eq!(eval("3x^2", 2.0), 12.0);
+        assert_eq!(eval("2(x + 1)", 1.0), 4.0);
+        assert_eq!(eval("2 pi", 0.0), 2.0 * PI);
+        assert_eq!(eval("2sin(x)", 0.5), 2.0 * 0.5f64.sin());
+    }
+
+    #[test]
+    fn exponents_and_the_constant_e() {
+        assert_eq!(eval("2e2", 0.0), 200.0);
+        assert_eq!(eval("2e-1", 0.0), 0.2);
+        assert_eq!(eval("1.5E+1", 0.0), 15.0);
+        assert_eq!(eval("2e", 0.0), 2.0 * E);
+        assert_eq!(eval("2e-x", 1.0), 2.0 * E - 1.0);
+        assert_eq!(eval("e^x", 1.0), E);
+    }
+
+    #[test]
+    fn names_ignore_case() {
+        assert_eq!(eval("PI", 0.0), PI);
+        assert_eq!(eval("Exp(X)", 1.0), E);
+        assert_eq!(eval("log(x)", E), 1.0);
+    }
+
+    #[test]
+    fn derivatives() {
+        let slope = |text: &str, x: f64| Expr::parse(text).unwrap().eval_with_derivative(x).1;
+        assert_eq!(slope("x^2", 3.0), 6.0);
+        assert_eq!(slope("3x + 1", 5.0), 3.0);
+        assert_eq!(slope("(-x)^3", 2.0), -12.0);
+        assert_eq!(slope("2^x", 1.0), 2.0 * LN_2);
+        assert_eq!(slope("sin(2x)", 0.0), 2.0);
+        assert_eq!(slope("1/x", 2.0), -0.25);
+        assert_eq!(slope("abs(x)", -1.0), -1.0);
+    }
+
+    #[test]
+    fn errors() {
+        let error = |text: &str| Expr::parse(text).unwrap_err();
+        assert_eq!(error(""), ExprError::Empty);
+        assert_eq!(error("  "), ExprError::Empty);
+        assert_eq!(error("1 +"), ExprError::UnexpectedEnd);
+        assert_eq!(error("(x + 1"), ExprError::ParenUnclosed);
+        assert_eq!(error("x + 1)"), ExprError::UnexpectedChar(')'));
+        assert_eq!(error("x $ 2"), ExprError::UnexpectedChar('$'));
+        assert_eq!(error("2 * * 3"), ExprError::UnexpectedChar('*'));
+        assert_eq!(error("foo(x)"), ExprError::UnknownName("foo".to_string()));
+        assert_eq!(
+            error("exp x"),
+            ExprError::ArgumentMissing("exp".to_string())
+        );
+    }
+}
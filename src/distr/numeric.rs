@@ -0,0 +1,448 @@
+//! Numerical helpers for the in-crate distributions that statrs does not cover.
+
+use statrs::{
+    function::{
+        erf::erfc,
+        gamma::{gamma_lr, ln_gamma},
+    },
+    statistics::Distribution,
+};
+use std::collections::VecDeque;
+
+/// Euler-Mascheroni constant `γ`.
+pub(crate) const EULER_MASCHERONI: f64 = 0.577_215_664_901_532_9;
+
+/// Exponentially scaled modified Bessel function of the first kind of order
+/// zero, `exp(-|x|) * I0(x)`.
+pub(crate) fn bessel_i0e(x: f64) -> f64 {
+    bessel_ie(0, x.abs())
+}
+
+/// Exponentially scaled modified Bessel function of the first kind of order
+/// one, `exp(-|x|) * I1(x)`.
+pub(crate) fn bessel_i1e(x: f64) -> f64 {
+    bessel_ie(1, x.abs()) * x.signum()
+}
+
+// Power series up to `SERIES_LIMIT`, the asymptotic expansion beyond that,
+// where it has converged to machine precision long before its terms diverge.
+fn bessel_ie(order: u32, x: f64) -> f64 {
+    const SERIES_LIMIT: f64 = 30.0;
+    let nu = order as f64;
+    if x <= SERIES_LIMIT {
+        let q = x * x / 4.0;
+        let mut term = (x / 2.0).powi(order as i32) / (1..=order).product::<u32>() as f64;
+        let mut sum = term;
+        let mut k = 1.0;
+        while term > sum * f64::EPSILON {
+            term *= q / (k * (k + nu));
+            sum += term;
+            k += 1.0;
+        }
+        sum * (-x).exp()
+    } else {
+        let mu = 4.0 * nu * nu;
+        let mut term = 1.0;
+        let mut sum = 1.0;
+        for k in 1..30 {
+            let j = (2 * k - 1) as f64;
+            term *= -(mu - j * j) / (k as f64 * 8.0 * x);
+            sum += term;
+            if term.abs() < sum.abs() * f64::EPSILON {
+                break;
+            }
+        }
+        sum / (2.0 * std::f64::consts::PI * x).sqrt()
+    }
+}
+
+/// `sum_k Pois(k; mu) * P(a + k, y)` with `P` the regularized lower incomplete
+/// gamma function, the CDF of noncentral chi-squared type distributions.
+///
+/// The sum starts at the mode of the Poisson weights and walks outwards,
+/// updating `P` with its recurrence instead of re-evaluating it per term.
+pub(crate) fn poisson_gamma_lr(mu: f64, a: f64, y: f64) -> f64 {
+    if y <= 0.0 {
+        return 0.0;
+    }
+    if mu <= 0.0 {
+        return gamma_lr(a, y);
+    }
+    let k0 = mu.floor();
+    let w0 = (-mu + k0 * mu.ln() - ln_gamma(k0 + 1.0)).exp();
+    let p0 = gamma_lr(a + k0, y);
+    // t(s) = y^s e^-y / Γ(s + 1), so that P(s + 1, y) = P(s, y) - t(s)
+    let t0 = ((a + k0) * y.ln() - y - ln_gamma(a + k0 + 1.0)).exp();
+    let mut sum = w0 * p0;
+    let spread = 10.0 * mu.sqrt() + 20.0;
+
+    let (mut w, mut p, mut t, mut k) = (w0, p0, t0, k0);
+    while k < mu + spread {
+        p = (p - t).max(0.0);
+        k += 1.0;
+        t *= y / (a + k);
+        w *= mu / k;
+        sum += w * p;
+    }
+
+    let (mut w, mut p, mut t, mut k) = (w0, p0, t0, k0);
+    while k > 0.0 && k > mu - spread {
+        // t(s - 1) = t(s) * s / y
+        t *= (a + k) / y;
+        p = (p + t).min(1.0);
+        w *= k / mu;
+        k -= 1.0;
+        sum += w * p;
+    }
+    sum.clamp(0.0, 1.0)
+}
+
+/// `sum_j Pois(j; mu) * term(j)`, the Poisson mixtures behind the noncentral
+/// distributions. Only the weights within a generous window around `mu`
+/// are summed, the rest is far below machine precision. The weights are
+/// normalized by their sum, for large `mu` each of them is off by the
+/// rounding error of exponents of size `mu`.
+pub(crate) fn poisson_mixture(mu: f64, term: impl Fn(f64) -> f64) -> f64 {
+    if mu <= 0.0 {
+        return term(0.0);
+    }
+    let spread = 10.0 * mu.sqrt() + 20.0;
+    let lo = (mu - spread).max(0.0).floor() as u64;
+    let hi = (mu + spread).ceil() as u64;
+    let (total, sum) = (lo..=hi)
+        .map(|j| {
+            let j = j as f64;
+            let w = (-mu + j * mu.ln() - ln_gamma(j + 1.0)).exp();
+            (w, w * term(j))
+        })
+        .fold((0.0, 0.0), |(total, sum), (w, t)| (total + w, sum + t));
+    sum / total
+}
+
+/// Quantile levels to split integrals over a whole distribution at, the
+/// outermost ones also cut off unbounded sides.
+pub(crate) const QUANTILE_LEVELS: [f64; 11] = [
+    1e-14,
+    1e-9,
+    1e-6,
+    1e-3,
+    0.1,
+    0.5,
+    0.9,
+    1.0 - 1e-3,
+    1.0 - 1e-6,
+    1.0 - 1e-9,
+    1.0 - 1e-14,
+];
+
+/// Range between the quantiles at `eps` and `1 - eps`, for the smallest
+/// `eps` at which it spans at most a thousand interquartile ranges.
+pub(crate) fn central_range(quantile: impl Fn(f64) -> f64) -> (f64, f64) {
+    const TAILS: [f64; 7] = [1e-12, 1e-10, 1e-8, 1e-6, 1e-5, 1e-4, 1e-3];
+    let iqr = quantile(0.75) - quantile(0.25);
+    let range = |eps: f64| (quantile(eps), quantile(1.0 - eps));
+    TAILS
+        .iter()
+        .map(|&eps| range(eps))
+        .find(|(lo, hi)| hi - lo <= 1000.0 * iqr)
+        .unwrap_or_else(|| range(TAILS[TAILS.len() - 1]))
+}
+
+/// Integral of `f` from the smallest to the largest of `points`, split at
+/// every point in between so that neither a narrow peak nor a long tail is
+/// missed. Non-finite points are dropped.
+///
+/// Adaptive Gauss-Kronrod (G7, K15) quadrature to a relative tolerance of
+/// about `1e-10`. Each range starts out in four pieces, so a narrow peak
+/// cannot hide between the nodes of a single rule.
+///
+/// The tolerance is relative to the whole integral, pieces where `f` is
+/// negligible are not refined on their own scale. Pieces are bisected level
+/// by level up to a fixed number of times, so that rounding noise in `f`,
+/// which no amount of refinement gets below the tolerance, cannot stall it.
+pub(crate) fn integrate_pieces(f: impl Fn(f64) -> f64, mut points: Vec<f64>) -> f64 {
+    points.retain(|x| x.is_finite());
+    points.sort_by(f64::total_cmp);
+    points.dedup();
+    let pieces: Vec<_> = points
+        .windows(2)
+        .flat_map(|w| {
+            let h = (w[1] - w[0]) / 4.0;
+            (0..4).map(move |i| (w[0] + i as f64 * h, w[0] + (i + 1) as f64 * h))
+        })
+        .map(|(lo, hi)| (lo, hi, gauss_kronrod(&f, lo, hi)))
+        .collect();
+    let scale: f64 = pieces.iter().map(|(_, _, (k, _))| k.abs()).sum();
+    let tol = (scale * 1e-10).max(f64::MIN_POSITIVE) / pieces.len().max(1) as f64;
+    let mut queue: VecDeque<_> = pieces
+        .into_iter()
+        .map(|(lo, hi, estimate)| (lo, hi, estimate, tol, 30))
+        .collect();
+    let mut bisections = 0;
+    let mut total = 0.0;
+    while let Some((lo, hi, (estimate, error), tol, depth)) = queue.pop_front() {
+        if error <= tol || depth == 0 || bisections == MAX_BISECTIONS {
+            total += estimate;
+            continue;
+        }
+        bisections += 1;
+        let mid = 0.5 * (lo + hi);
+        queue.push_back((lo, mid, gauss_kronrod(&f, lo, mid), tol / 2.0, depth - 1));
+        queue.push_back((mid, hi, gauss_kronrod(&f, mid, hi), tol / 2.0, depth - 1));
+    }
+    total
+}
+
+/// Most pieces a single integral bisects.
+const MAX_BISECTIONS: usize = 1000;
+
+/// Integral of `f` split at `points` as by `integrate_pieces`, or `None`
+/// where the stretches in `suspect` still carry a percent of the integral of
+/// `|f|`, or that is not finite. With the outermost tails and the
+/// surroundings of singularities as the suspects, that is the mark of an
+/// integral that diverges or converges too slowly to be trusted.
+pub(crate) fn converged_integral(
+    f: impl Fn(f64) -> f64,
+    points: Vec<f64>,
+    suspect: &[(f64, f64)],
+) -> Option<f64> {
+    let size = |points: Vec<f64>| integrate_pieces(|x| f(x).abs(), points);
+    let total = size(points.clone());
+    let edges: f64 = suspect.iter().map(|&(lo, hi)| size(vec![lo, hi])).sum();
+    (total.is_finite() && edges <= 0.01 * total).then(|| integrate_pieces(&f, points))
+}
+
+// Kronrod nodes on [0, 1) and their weights, every other node is also a
+// node of the embedded 7-point Gauss rule.
+const KRONROD_NODES: [f64; 8] = [
+    0.991_455_371_120_812_6,
+    0.949_107_912_342_758_5,
+    0.864_864_423_359_769_1,
+    0.741_531_185_599_394_4,
+    0.586_087_235_467_691_1,
+    0.405_845_151_377_397_2,
+    0.207_784_955_007_898_5,
+    0.0,
+];
+const KRONROD_WEIGHTS: [f64; 8] = [
+    0.022_935_322_010_529_22,
+    0.063_092_092_629_978_55,
+    0.104_790_010_322_250_2,
+    0.140_653_259_715_525_9,
+    0.169_004_726_639_267_9,
+    0.190_350_578_064_785_4,
+    0.204_432_940_075_298_9,
+    0.209_482_141_084_727_8,
+];
+const GAUSS_WEIGHTS: [f64; 4] = [
+    0.129_484_966_168_869_7,
+    0.279_705_391_489_276_7,
+    0.381_830_050_505_118_9,
+    0.417_959_183_673_469_4,
+];
+
+/// Kronrod estimate of the integral over `[a, b]` and its difference to the
+/// Gauss estimate as error bound.
+fn gauss_kronrod(f: &impl Fn(f64) -> f64, a: f64, b: f64) -> (f64, f64) {
+    let (center, half) = (0.5 * (a + b), 0.5 * (b - a));
+    let (mut kronrod, mut gauss) = (0.0, 0.0);
+    for (i, (&x, &w)) in KRONROD_NODES.iter().zip(KRONROD_WEIGHTS.iter()).enumerate() {
+        let y = if x == 0.0 {
+            f(center)
+        } else {
+            f(center - half * x) + f(center + half * x)
+        };
+        kronrod += w * y;
+        if i % 2 == 1 {
+            gauss += GAUSS_WEIGHTS[i / 2] * y;
+        }
+    }
+    (kronrod * half, ((kronrod - gauss) * half).abs())
+}
+
+/// Inverts a non-decreasing `cdf` by bisection. `lo` and `hi` only need to be
+/// initial guesses, they are widened until they bracket `p`.
+pub(crate) fn invert_cdf(cdf: impl Fn(f64) -> f64, p: f64, mut lo: f64, mut hi: f64) -> f64 {
+    // an infinite end stops the widening, should `cdf` never get to `p`
+    let mut step = (hi - lo).max(1.0);
+    while cdf(lo) > p && lo.is_finite() {
+        lo -= step;
+        step *= 2.0;
+    }
+    let mut step = (hi - lo).max(1.0);
+    while cdf(hi) < p && hi.is_finite() {
+        hi += step;
+        step *= 2.0;
+    }
+    for _ in 0..200 {
+        let mid = 0.5 * (lo + hi);
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        if cdf(mid) < p {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    0.5 * (lo + hi)
+}
+
+/// Owen's T function `T(h, a) = 1/2π ∫_0^a exp(-h²(1 + x²)/2) / (1 + x²) dx`.
+pub(crate) fn owens_t(h: f64, a: f64) -> f64 {
+    let h = h.abs();
+    if a < 0.0 {
+        return -owens_t(h, -a);
+    }
+    if a == 0.0 || h > 40.0 {
+        return 0.0;
+    }
+    if a > 1.0 {
+        // reflect onto a <= 1 where the integrand is well behaved
+        let (ph, pah) = (std_normal_cdf(h), std_normal_cdf(a * h));
+        return 0.5 * ph + 0.5 * pah - ph * pah - owens_t(a * h, 1.0 / a);
+    }
+    // Simpson's rule, the integrand narrows to a width of about 1/h
+    let n = 2 * (50 + 20 * h.ceil() as usize);
+    let step = a / n as f64;
+    let f = |x: f64| (-0.5 * h * h * (1.0 + x * x)).exp() / (1.0 + x * x);
+    let inner: f64 = (1..n)
+        .map(|i| if i % 2 == 1 { 4.0 } else { 2.0 } * f(i as f64 * step))
+        .sum();
+    (f(0.0) + inner + f(a)) * step / (6.0 * std::f64::consts::PI)
+}
+
+/// CDF of the standard normal distribution.
+pub(crate) fn std_normal_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x * std::f64::consts::FRAC_1_SQRT_2)
+}
+
+/// Mean, variance and skewness of a mixture from those of its components and
+/// their weights, which must sum to one. Each is `None` as soon as one of the
+/// components lacks it.
+pub(crate) fn mixture_moments(
+    parts: &[(f64, &dyn Distribution<f64>)],
+) -> (Option<f64>, Option<f64>, Option<f64>) {
+    let Some(mean) = parts
+        .iter()
+        .map(|(w, d)| d.mean().map(|m| w * m))
+        .sum::<Option<f64>>()
+    else {
+        return (None, None, None);
+    };
+    // E[(X - μ)^2] and E[(X - μ)^3] of each component around the common mean
+    let central = |r: i32| {
+        parts
+            .iter()
+            .map(|(w, d)| {
+                let delta = d.mean()? - mean;
+                let var = d.variance()?;
+                let term = match r {
+                    2 => var + delta * delta,
+                    _ => d.skewness()? * var.powf(1.5) + 3.0 * var * delta + delta.powi(3),
+                };
+                Some(w * term)
+            })
+            .sum::<Option<f64>>()
+    };
+    let var = central(2);
+    let skewness = match var {
+        Some(v) if v > 0.0 => central(3).map(|m3| m3 / v.powf(1.5)),
+        _ => None,
+    };
+    (Some(mean), var, skewness)
+}
+
+/// Mean, variance and skewness from the first three raw moments.
+pub(crate) fn moments_from_raw(raw: [Option<f64>; 3]) -> (Option<f64>, Option<f64>, Option<f64>) {
+    let [m1, m2, m3] = raw;
+    let var = m1.zip(m2).map(|(m1, m2)| (m2 - m1 * m1).max(0.0));
+    let skewness = match (m1, var, m3) {
+        (Some(m1), Some(v), Some(m3)) if v > 0.0 => {
+            Some((m3 - 3.0 * m1 * v - m1.powi(3)) / v.powf(1.5))
+        }
+        _ => None,
+    };
+    (m1, var, skewness)
+}
+
+/// The first three raw moments `E[X^r]` of `d`, from its mean, variance and
+/// skewness as far as it reports them.
+pub(crate) fn raw_moments(d: &dyn Distribution<f64>) -> [Option<f64>; 3] {
+    let (mean, var) = (d.mean(), d.variance());
+    let m2 = mean.zip(var).map(|(m, v)| v + m * m);
+    let m3 = mean
+        .zip(var)
+        .zip(d.skewness())
+        .map(|((m, v), g)| g * v.powf(1.5) + 3.0 * m * v + m.powi(3));
+    [mean, m2, m3]
+}
+
+/// Mean, variance and skewness of a sum of independent variables, each
+/// distribution paired with the number of times it is added. The first three
+/// cumulants add up over the terms.
+pub(crate) fn sum_moments(
+    terms: &[(u64, &dyn Distribution<f64>)],
+) -> (Option<f64>, Option<f64>, Option<f64>) {
+    let cumulant = |f: &dyn Fn(&dyn Distribution<f64>) -> Option<f64>| {
+        terms
+            .iter()
+            .map(|&(n, d)| f(d).map(|k| n as f64 * k))
+            .sum::<Option<f64>>()
+    };
+    let mean = cumulant(&|d| d.mean());
+    let var = cumulant(&|d| d.variance());
+    let skewness = match var {
+        Some(v) if v > 0.0 => {
+            cumulant(&|d| Some(d.skewness()? * d.variance()?.powf(1.5))).map(|k3| k3 / v.powf(1.5))
+        }
+        _ => None,
+    };
+    (mean, var, skewness)
+}
+
+/// In-place radix-2 fast Fourier transform of the sequence `re + i·im`,
+/// whose length must be a power of two. The inverse transform includes the
+/// `1/n` normalization.
+pub(crate) fn fft(re: &mut [f64], im: &mut [f64], inverse: bool) {
+    let n = re.len();
+    debug_assert!(n.is_power_of_two() && im.len() == n);
+    // bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut len = 2;
+    while len <= n {
+        let angle = sign * 2.0 * std::f64::consts::PI / len as f64;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                // twiddle factors from the angle directly, a running product
+                // would accumulate rounding errors over long transforms
+                let (w_im, w_re) = (angle * k as f64).sin_cos();
+                let (a, b) = (start + k, start + k + len / 2);
+                let t_re = re[b] * w_re - im[b] * w_im;
+                let t_im = re[b] * w_im + im[b] * w_re;
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
+            }
+        }
+        len <<= 1;
+    }
+    if inverse {
+        let scale = 1.0 / n as f64;
+        re.iter_mut().chain(im.iter_mut()).for_each(|x| *x *= scale);
+    }
+}